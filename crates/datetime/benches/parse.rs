@@ -0,0 +1,61 @@
+//! A small benchmark over many date strings, without pulling in a
+//! benchmarking framework. Run with `cargo bench -p whatwg-datetime`.
+
+use std::hint::black_box;
+use std::time::Instant;
+use whatwg_datetime::{parse_date, parse_local_datetime, parse_month, parse_time, parse_week};
+
+const ITERATIONS: u32 = 20;
+
+fn date_strings() -> Vec<String> {
+	let mut dates = Vec::new();
+	for year in 1970..2070 {
+		for month in 1..=12 {
+			for day in 1..=28 {
+				dates.push(format!("{:04}-{:02}-{:02}", year, month, day));
+			}
+		}
+	}
+
+	dates
+}
+
+fn bench<T>(name: &str, inputs: &[String], parse_fn: impl Fn(&str) -> Option<T>) {
+	let start = Instant::now();
+	for _ in 0..ITERATIONS {
+		for input in inputs {
+			black_box(parse_fn(black_box(input)));
+		}
+	}
+
+	let elapsed = start.elapsed();
+	let total = inputs.len() as u32 * ITERATIONS;
+	println!(
+		"{:<24} {:>10.1} ns/iter",
+		name,
+		elapsed.as_nanos() as f64 / f64::from(total)
+	);
+}
+
+fn main() {
+	let dates = date_strings();
+	let months: Vec<String> = dates.iter().map(|d| d[..7].to_owned()).collect();
+	let datetimes: Vec<String> = dates
+		.iter()
+		.map(|d| format!("{}T14:54:39.929", d))
+		.collect();
+	let times: Vec<String> = (0..24)
+		.flat_map(|hour| {
+			(0..60).map(move |minute| format!("{:02}:{:02}:39.929", hour, minute))
+		})
+		.collect();
+	let weeks: Vec<String> = (1970..2070)
+		.flat_map(|year| (1..=52).map(move |week| format!("{:04}-W{:02}", year, week)))
+		.collect();
+
+	bench("parse_month", &months, parse_month);
+	bench("parse_date", &dates, parse_date);
+	bench("parse_local_datetime", &datetimes, parse_local_datetime);
+	bench("parse_time", &times, parse_time);
+	bench("parse_week", &weeks, parse_week);
+}
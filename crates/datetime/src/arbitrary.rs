@@ -0,0 +1,93 @@
+use crate::utils::{max_days_in_month_year, week_number_of_year};
+use crate::{TimeZoneOffset, YearMonth, YearWeek, YearlessDate};
+use arbitrary::{Arbitrary, Result, Unstructured};
+
+// The maximum year chrono can represent, so that every generated
+// value can also be converted into chrono's types
+const MAX_YEAR: i32 = 262_142;
+const MAX_OFFSET_MINUTES: i32 = 23 * 60 + 59;
+
+impl<'a> Arbitrary<'a> for YearMonth {
+	fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+		let year = u.int_in_range(1..=MAX_YEAR)?;
+		let month = u.int_in_range(1..=12)?;
+		Ok(YearMonth::new(year, month))
+	}
+}
+
+impl<'a> Arbitrary<'a> for YearWeek {
+	fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+		let year = u.int_in_range(1..=MAX_YEAR)?;
+		let max_weeks = week_number_of_year(year).unwrap();
+		let week = u.int_in_range(1..=max_weeks)?;
+		Ok(YearWeek::new(year, week))
+	}
+}
+
+impl<'a> Arbitrary<'a> for YearlessDate {
+	fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+		let month = u.int_in_range(1..=12)?;
+		let max_days = max_days_in_month_year(month, 4).unwrap();
+		let day = u.int_in_range(1..=max_days)?;
+		Ok(YearlessDate::new(month, day))
+	}
+}
+
+impl<'a> Arbitrary<'a> for TimeZoneOffset {
+	fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+		let total_minutes = u.int_in_range(-MAX_OFFSET_MINUTES..=MAX_OFFSET_MINUTES)?;
+		Ok(TimeZoneOffset::new(total_minutes / 60, total_minutes % 60))
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use crate::{TimeZoneOffset, YearMonth, YearWeek, YearlessDate};
+	use arbitrary::{Arbitrary, Unstructured};
+
+	const DATA: &[u8] = &[
+		0x9f, 0x3c, 0xe2, 0x07, 0x51, 0xaa, 0x18, 0x6d, 0xf0, 0x42, 0x0b, 0xc7, 0x35, 0x88,
+		0x2e, 0xd4, 0x61, 0x13, 0xfe, 0x7a, 0x04, 0xb9, 0x56, 0x99, 0x20, 0xe8, 0x3f, 0x71,
+	];
+
+	#[test]
+	fn test_arbitrary_year_month() {
+		let mut u = Unstructured::new(DATA);
+		for _ in 0..8 {
+			let value = YearMonth::arbitrary(&mut u).unwrap();
+			assert_eq!(YearMonth::new_opt(value.year(), value.month()), Some(value));
+		}
+	}
+
+	#[test]
+	fn test_arbitrary_year_week() {
+		let mut u = Unstructured::new(DATA);
+		for _ in 0..8 {
+			let value = YearWeek::arbitrary(&mut u).unwrap();
+			assert_eq!(YearWeek::new_opt(value.year(), value.week()), Some(value));
+		}
+	}
+
+	#[test]
+	fn test_arbitrary_yearless_date() {
+		let mut u = Unstructured::new(DATA);
+		for _ in 0..8 {
+			let value = YearlessDate::arbitrary(&mut u).unwrap();
+			assert_eq!(
+				YearlessDate::new_opt(value.month(), value.day()),
+				Some(value)
+			);
+		}
+	}
+
+	#[test]
+	fn test_arbitrary_timezone_offset() {
+		let mut u = Unstructured::new(DATA);
+		for _ in 0..8 {
+			let value = TimeZoneOffset::arbitrary(&mut u).unwrap();
+			assert!((-23..=23).contains(&value.hour()));
+			assert!((-59..=59).contains(&value.minute()));
+			assert!(value.hour() == 0 || value.hour().signum() * value.minute() >= 0);
+		}
+	}
+}
@@ -0,0 +1,312 @@
+use crate::tokens::Token;
+use crate::{
+	parse_date, parse_global_datetime, parse_local_datetime, parse_month, parse_time,
+	parse_week, parse_yearless_date, YearMonth, YearWeek, YearlessDate,
+};
+use chrono::{DateTime, NaiveDate, NaiveDateTime, NaiveTime, Utc};
+
+/// A parsed value of any of the datetime microsyntaxes
+///
+/// This is returned by [`parse_any_datetime`], for inputs where the
+/// format isn't known ahead of time.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DateTimeValue {
+	/// A [month string](crate::parse_month)
+	Month(YearMonth),
+	/// A [date string](crate::parse_date)
+	Date(NaiveDate),
+	/// A [yearless date string](crate::parse_yearless_date)
+	YearlessDate(YearlessDate),
+	/// A [time string](crate::parse_time)
+	Time(NaiveTime),
+	/// A [local date and time string](crate::parse_local_datetime)
+	LocalDateTime(NaiveDateTime),
+	/// A [global date and time string](crate::parse_global_datetime)
+	GlobalDateTime(DateTime<Utc>),
+	/// A [week string](crate::parse_week)
+	Week(YearWeek),
+}
+
+/// Parse a string in any of the datetime microsyntaxes, detecting which
+/// format it is in
+///
+/// Each format is tried in the following order, returning the first one
+/// which consumes the entire input:
+///  1. [local date and time](crate::parse_local_datetime), e.g `2011-11-18T14:54`
+///  2. [global date and time](crate::parse_global_datetime), e.g `2011-11-18T14:54Z`
+///  3. [date](crate::parse_date), e.g `2011-11-18`
+///  4. [month](crate::parse_month), e.g `2011-11`
+///  5. [week](crate::parse_week), e.g `2011-W47`
+///  6. [yearless date](crate::parse_yearless_date), e.g `11-18`
+///  7. [time](crate::parse_time), e.g `14:54`
+///
+/// Local dates and times are tried before global dates and times, since
+/// a global date and time string without a time-zone offset is also a
+/// local date and time string. None of the other formats overlap; for example,
+/// `2011-11` is always a month, since a date must also have a day.
+///
+/// # Examples
+/// ```
+/// use whatwg_datetime::{parse_any_datetime, DateTimeValue, YearMonth};
+///
+/// assert_eq!(
+///     parse_any_datetime("2011-11"),
+///     YearMonth::new_opt(2011, 11).map(DateTimeValue::Month)
+/// );
+/// assert!(matches!(
+///     parse_any_datetime("2011-11-18T14:54"),
+///     Some(DateTimeValue::LocalDateTime(_))
+/// ));
+/// assert!(matches!(
+///     parse_any_datetime("2011-11-18T14:54Z"),
+///     Some(DateTimeValue::GlobalDateTime(_))
+/// ));
+/// assert_eq!(parse_any_datetime("2011-11-18T"), None);
+/// ```
+pub fn parse_any_datetime(s: &str) -> Option<DateTimeValue> {
+	parse_local_datetime(s)
+		.map(DateTimeValue::LocalDateTime)
+		.or_else(|| parse_global_datetime(s).map(DateTimeValue::GlobalDateTime))
+		.or_else(|| parse_date(s).map(DateTimeValue::Date))
+		.or_else(|| parse_month(s).map(DateTimeValue::Month))
+		.or_else(|| parse_week(s).map(DateTimeValue::Week))
+		.or_else(|| parse_yearless_date(s).map(DateTimeValue::YearlessDate))
+		.or_else(|| parse_time(s).map(DateTimeValue::Time))
+}
+
+/// The kind of a datetime microsyntax, as guessed by [`detect_datetime_kind`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum DateTimeKind {
+	/// A [month string](crate::parse_month)
+	Month,
+	/// A [date string](crate::parse_date)
+	Date,
+	/// A [yearless date string](crate::parse_yearless_date)
+	YearlessDate,
+	/// A [time string](crate::parse_time)
+	Time,
+	/// A [local date and time string](crate::parse_local_datetime)
+	LocalDateTime,
+	/// A [global date and time string](crate::parse_global_datetime)
+	GlobalDateTime,
+	/// A [week string](crate::parse_week)
+	Week,
+}
+
+/// Guess which datetime microsyntax a string is written in, from its shape
+///
+/// This is a heuristic, which only looks at the separators in the string
+/// (`T` or a space, `:`, `W`, and the number of hyphen-separated groups).
+/// It doesn't validate any of the components, so a string may be detected
+/// as a kind that it then fails to parse as; for example, `2011-13` is
+/// detected as a [`DateTimeKind::Month`]. To fully parse a string of an
+/// unknown format, use [`parse_any_datetime`] instead.
+///
+/// # Examples
+/// ```
+/// use whatwg_datetime::{detect_datetime_kind, DateTimeKind};
+///
+/// assert_eq!(detect_datetime_kind("2011-11"), Some(DateTimeKind::Month));
+/// assert_eq!(detect_datetime_kind("2011-W47"), Some(DateTimeKind::Week));
+/// assert_eq!(detect_datetime_kind("2011-11-18T14:54Z"), Some(DateTimeKind::GlobalDateTime));
+/// assert_eq!(detect_datetime_kind("2011-13"), Some(DateTimeKind::Month));
+/// assert_eq!(detect_datetime_kind("hello"), None);
+/// ```
+pub fn detect_datetime_kind(s: &str) -> Option<DateTimeKind> {
+	if let Some(index) = s.find([Token::T, Token::SPACE]) {
+		let (date, time) = (&s[..index], &s[index + 1..]);
+		if detect_date_kind(date)? != DateTimeKind::Date || !time.contains(Token::COLON) {
+			return None;
+		}
+
+		let has_offset = time.contains([Token::Z, Token::PLUS, Token::MINUS]);
+		return Some(if has_offset {
+			DateTimeKind::GlobalDateTime
+		} else {
+			DateTimeKind::LocalDateTime
+		});
+	}
+
+	if s.contains(Token::COLON) {
+		return if s.starts_with(|c: char| c.is_ascii_digit()) {
+			Some(DateTimeKind::Time)
+		} else {
+			None
+		};
+	}
+
+	detect_date_kind(s)
+}
+
+// Detects the kind of a string which has no time component, by
+// counting its hyphen-separated groups of digits
+fn detect_date_kind(s: &str) -> Option<DateTimeKind> {
+	if let Some(rest) = s.strip_prefix("--") {
+		return match group_lengths(rest)? {
+			(2, Some(_), None) => Some(DateTimeKind::YearlessDate),
+			_ => None,
+		};
+	}
+
+	if let Some((year, week)) = s.split_once(Token::HYPHEN) {
+		if let Some(week) = week.strip_prefix(Token::ABBR_WEEK) {
+			return match group_lengths(year)? {
+				(_, None, None) if is_ascii_digits(week) => {
+					Some(DateTimeKind::Week)
+				}
+				_ => None,
+			};
+		}
+	}
+
+	match group_lengths(s)? {
+		(2, Some(_), None) => Some(DateTimeKind::YearlessDate),
+		(_, Some(_), None) => Some(DateTimeKind::Month),
+		(_, Some(_), Some(_)) => Some(DateTimeKind::Date),
+		_ => None,
+	}
+}
+
+// The lengths of up to three hyphen-separated groups of ASCII digits,
+// or `None` if there are more groups or any group isn't all digits
+fn group_lengths(s: &str) -> Option<(usize, Option<usize>, Option<usize>)> {
+	let mut groups = s.split(Token::HYPHEN);
+	let mut next = || {
+		groups.next()
+			.filter(|group| is_ascii_digits(group))
+			.map(str::len)
+	};
+	let first = next()?;
+	let (second, third) = (next(), next());
+	if groups.next().is_some() || (second.is_none() && third.is_some()) {
+		return None;
+	}
+
+	Some((first, second, third))
+}
+
+fn is_ascii_digits(s: &str) -> bool {
+	!s.is_empty() && s.bytes().all(|b| b.is_ascii_digit())
+}
+
+#[cfg(test)]
+mod tests {
+	use super::{detect_datetime_kind, parse_any_datetime, DateTimeKind, DateTimeValue};
+	use crate::{YearMonth, YearWeek, YearlessDate};
+	use chrono::{NaiveDate, NaiveDateTime, NaiveTime, TimeZone, Utc};
+
+	#[test]
+	fn test_parse_any_datetime_month() {
+		assert_eq!(
+			parse_any_datetime("2011-11"),
+			Some(DateTimeValue::Month(YearMonth::new(2011, 11)))
+		);
+	}
+
+	#[test]
+	fn test_parse_any_datetime_date() {
+		assert_eq!(
+			parse_any_datetime("2011-11-18"),
+			NaiveDate::from_ymd_opt(2011, 11, 18).map(DateTimeValue::Date)
+		);
+	}
+
+	#[test]
+	fn test_parse_any_datetime_yearless_date() {
+		assert_eq!(
+			parse_any_datetime("11-18"),
+			Some(DateTimeValue::YearlessDate(YearlessDate::new(11, 18)))
+		);
+	}
+
+	#[test]
+	fn test_parse_any_datetime_time() {
+		assert_eq!(
+			parse_any_datetime("14:54:39"),
+			NaiveTime::from_hms_opt(14, 54, 39).map(DateTimeValue::Time)
+		);
+	}
+
+	#[test]
+	fn test_parse_any_datetime_local_datetime() {
+		assert_eq!(
+			parse_any_datetime("2011-11-18 14:54"),
+			Some(DateTimeValue::LocalDateTime(NaiveDateTime::new(
+				NaiveDate::from_ymd_opt(2011, 11, 18).unwrap(),
+				NaiveTime::from_hms_opt(14, 54, 0).unwrap(),
+			)))
+		);
+	}
+
+	#[test]
+	fn test_parse_any_datetime_global_datetime() {
+		assert_eq!(
+			parse_any_datetime("2011-11-18T14:54-01:00"),
+			Some(DateTimeValue::GlobalDateTime(Utc.from_utc_datetime(
+				&NaiveDateTime::new(
+					NaiveDate::from_ymd_opt(2011, 11, 18).unwrap(),
+					NaiveTime::from_hms_opt(15, 54, 0).unwrap(),
+				)
+			)))
+		);
+	}
+
+	#[test]
+	fn test_parse_any_datetime_week() {
+		assert_eq!(
+			parse_any_datetime("2011-W47"),
+			Some(DateTimeValue::Week(YearWeek::new(2011, 47)))
+		);
+	}
+
+	#[test]
+	fn test_parse_any_datetime_fails() {
+		assert_eq!(parse_any_datetime(""), None);
+		assert_eq!(parse_any_datetime("2011"), None);
+		assert_eq!(parse_any_datetime("2011-11-18T"), None);
+		assert_eq!(parse_any_datetime("2011-W47T14:54"), None);
+	}
+
+	#[test]
+	fn test_detect_datetime_kind() {
+		#[rustfmt::skip]
+		let cases = [
+			("2011-11", DateTimeKind::Month),
+			("2011-11-18", DateTimeKind::Date),
+			("--11-18", DateTimeKind::YearlessDate),
+			("11-18", DateTimeKind::YearlessDate),
+			("14:54", DateTimeKind::Time),
+			("14:54:39.929", DateTimeKind::Time),
+			("2011-11-18T14:54", DateTimeKind::LocalDateTime),
+			("2011-11-18 14:54:39", DateTimeKind::LocalDateTime),
+			("2011-11-18T14:54Z", DateTimeKind::GlobalDateTime),
+			("2011-11-18T14:54+05:30", DateTimeKind::GlobalDateTime),
+			("2011-11-18 14:54-07:00", DateTimeKind::GlobalDateTime),
+			("2011-W47", DateTimeKind::Week),
+		];
+
+		for (s, expected) in cases {
+			assert_eq!(detect_datetime_kind(s), Some(expected), "{}", s);
+		}
+	}
+
+	#[test]
+	fn test_detect_datetime_kind_does_not_validate() {
+		assert_eq!(detect_datetime_kind("2011-13"), Some(DateTimeKind::Month));
+		assert_eq!(detect_datetime_kind("2011-02-31"), Some(DateTimeKind::Date));
+		assert_eq!(detect_datetime_kind("99:99"), Some(DateTimeKind::Time));
+		assert_eq!(detect_datetime_kind("2011-W99"), Some(DateTimeKind::Week));
+	}
+
+	#[test]
+	fn test_detect_datetime_kind_fails() {
+		assert_eq!(detect_datetime_kind(""), None);
+		assert_eq!(detect_datetime_kind("2011"), None);
+		assert_eq!(detect_datetime_kind("hello"), None);
+		assert_eq!(detect_datetime_kind("2011-11-18T"), None);
+		assert_eq!(detect_datetime_kind("2011-11T14:54"), None);
+		assert_eq!(detect_datetime_kind("2011-11-18-19"), None);
+		assert_eq!(detect_datetime_kind("--2011-11-18"), None);
+		assert_eq!(detect_datetime_kind("2011-W47T14:54"), None);
+	}
+}
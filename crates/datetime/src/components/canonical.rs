@@ -0,0 +1,389 @@
+use crate::tokens::Token;
+use crate::{
+	serialize_timezone_offset, DateTimeValue, DurationComponents, TimeZoneOffset, YearMonth,
+	YearWeek, YearlessDate,
+};
+use chrono::{DateTime, Datelike, NaiveDate, NaiveDateTime, NaiveTime, Timelike, Utc};
+use core::fmt;
+
+/// Serialization of a datetime value into its canonical string
+///
+/// The canonical string is the [best representation][whatwg-html-best] of
+/// a value per the WHATWG HTML Standard, and can be parsed back into the
+/// same value. Times use their shortest form, omitting the seconds when
+/// they are zero, and omitting trailing zeros of a fraction of a second.
+///
+/// # Examples
+/// ```
+/// use whatwg_datetime::{parse_month, parse_time, CanonicalString};
+///
+/// assert_eq!(parse_month("2011-11").unwrap().to_canonical_string(), "2011-11");
+/// assert_eq!(parse_time("14:54:00.000").unwrap().to_canonical_string(), "14:54");
+/// ```
+///
+/// [whatwg-html-best]: https://html.spec.whatwg.org/multipage/common-microsyntaxes.html#concept-datetime-best-representation
+pub trait CanonicalString {
+	/// Serializes the value into its canonical string
+	fn to_canonical_string(&self) -> String;
+}
+
+impl CanonicalString for YearMonth {
+	fn to_canonical_string(&self) -> String {
+		format!("{:04}{}{:02}", self.year, Token::HYPHEN, self.month)
+	}
+}
+
+impl CanonicalString for NaiveDate {
+	fn to_canonical_string(&self) -> String {
+		format!(
+			"{:04}{}{:02}{}{:02}",
+			self.year(),
+			Token::HYPHEN,
+			self.month(),
+			Token::HYPHEN,
+			self.day()
+		)
+	}
+}
+
+impl CanonicalString for YearlessDate {
+	fn to_canonical_string(&self) -> String {
+		format!("{:02}{}{:02}", self.month, Token::HYPHEN, self.day)
+	}
+}
+
+impl CanonicalString for NaiveTime {
+	fn to_canonical_string(&self) -> String {
+		let mut s = format!("{:02}{}{:02}", self.hour(), Token::COLON, self.minute());
+
+		// A leap second can't be represented, so its fraction is dropped
+		let nanoseconds = self.nanosecond() % 1_000_000_000;
+		if self.second() != 0 || nanoseconds != 0 {
+			s.push_str(&format!("{}{:02}", Token::COLON, self.second()));
+		}
+		if nanoseconds != 0 {
+			let fraction = format!("{:09}", nanoseconds);
+			s.push(Token::DOT);
+			s.push_str(fraction.trim_end_matches('0'));
+		}
+
+		s
+	}
+}
+
+impl CanonicalString for NaiveDateTime {
+	fn to_canonical_string(&self) -> String {
+		format!(
+			"{}{}{}",
+			self.date().to_canonical_string(),
+			Token::T,
+			self.time().to_canonical_string()
+		)
+	}
+}
+
+impl CanonicalString for DateTime<Utc> {
+	fn to_canonical_string(&self) -> String {
+		format!("{}{}", self.naive_utc().to_canonical_string(), Token::Z)
+	}
+}
+
+impl CanonicalString for TimeZoneOffset {
+	fn to_canonical_string(&self) -> String {
+		serialize_timezone_offset(self)
+	}
+}
+
+impl CanonicalString for YearWeek {
+	fn to_canonical_string(&self) -> String {
+		format!(
+			"{:04}{}{}{:02}",
+			self.year,
+			Token::HYPHEN,
+			Token::ABBR_WEEK,
+			self.week
+		)
+	}
+}
+
+impl CanonicalString for DurationComponents {
+	fn to_canonical_string(&self) -> String {
+		let mut s = String::from(Token::ABBR_PERIOD);
+		if self.days != 0 {
+			s.push_str(&format!("{}{}", self.days, Token::ABBR_DAY));
+		}
+
+		let has_time = self.hours != 0 || self.minutes != 0;
+		let has_seconds = self.seconds != 0
+			|| self.milliseconds != 0
+			|| (!has_time && self.days == 0);
+		if !has_time && !has_seconds {
+			return s;
+		}
+
+		s.push(Token::T);
+		if self.hours != 0 {
+			s.push_str(&format!("{}{}", self.hours, Token::ABBR_HOUR));
+		}
+		if self.minutes != 0 {
+			s.push_str(&format!("{}{}", self.minutes, Token::ABBR_MIN));
+		}
+		if has_seconds {
+			s.push_str(&self.seconds.to_string());
+			if self.milliseconds != 0 {
+				let fraction = format!("{:03}", self.milliseconds);
+				s.push(Token::DOT);
+				s.push_str(fraction.trim_end_matches('0'));
+			}
+			s.push(Token::ABBR_SEC);
+		}
+
+		s
+	}
+}
+
+impl CanonicalString for DateTimeValue {
+	fn to_canonical_string(&self) -> String {
+		match self {
+			DateTimeValue::Month(value) => value.to_canonical_string(),
+			DateTimeValue::Date(value) => value.to_canonical_string(),
+			DateTimeValue::YearlessDate(value) => value.to_canonical_string(),
+			DateTimeValue::Time(value) => value.to_canonical_string(),
+			DateTimeValue::LocalDateTime(value) => value.to_canonical_string(),
+			DateTimeValue::GlobalDateTime(value) => value.to_canonical_string(),
+			DateTimeValue::Week(value) => value.to_canonical_string(),
+		}
+	}
+}
+
+// Debug-formats each value type as its canonical string, e.g `YearMonth("2011-11")`,
+// which is easier to read in test failures than each of its fields
+macro_rules! impl_debug_canonical {
+	($($ty:ident),+ $(,)?) => {
+		$(
+			impl fmt::Debug for $ty {
+				fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+					f.debug_tuple(stringify!($ty))
+						.field(&self.to_canonical_string())
+						.finish()
+				}
+			}
+		)+
+	};
+}
+
+impl_debug_canonical!(
+	YearMonth,
+	YearlessDate,
+	TimeZoneOffset,
+	YearWeek,
+	DurationComponents,
+);
+
+#[cfg(test)]
+mod tests {
+	use super::CanonicalString;
+	use crate::{
+		parse_any_datetime, parse_date, parse_duration_components, parse_global_datetime,
+		parse_local_datetime, parse_month, parse_time, parse_timezone_offset, parse_week,
+		parse_yearless_date, DurationComponents, TimeZoneOffset, YearMonth, YearWeek,
+		YearlessDate,
+	};
+
+	#[test]
+	fn test_debug_year_month() {
+		assert_eq!(
+			format!("{:?}", YearMonth::new(2011, 11)),
+			r#"YearMonth("2011-11")"#
+		);
+	}
+
+	#[test]
+	fn test_debug_yearless_date() {
+		assert_eq!(
+			format!("{:?}", YearlessDate::new(11, 18)),
+			r#"YearlessDate("11-18")"#
+		);
+	}
+
+	#[test]
+	fn test_debug_timezone_offset() {
+		assert_eq!(
+			format!("{:?}", TimeZoneOffset::new(-7, 0)),
+			r#"TimeZoneOffset("-07:00")"#
+		);
+		assert_eq!(
+			format!("{:?}", TimeZoneOffset::new(0, 0)),
+			r#"TimeZoneOffset("Z")"#
+		);
+	}
+
+	#[test]
+	fn test_debug_year_week() {
+		assert_eq!(
+			format!("{:?}", YearWeek::new(2004, 53)),
+			r#"YearWeek("2004-W53")"#
+		);
+	}
+
+	#[test]
+	fn test_debug_duration_components() {
+		assert_eq!(
+			format!("{:?}", DurationComponents::new(1, 2, 0, 0, 0)),
+			r#"DurationComponents("P1DT2H")"#
+		);
+	}
+
+	#[test]
+	fn test_debug_alternate() {
+		assert_eq!(
+			format!("{:#?}", YearMonth::new(2011, 11)),
+			"YearMonth(\n    \"2011-11\",\n)"
+		);
+	}
+
+	#[test]
+	fn test_debug_option() {
+		assert_eq!(
+			format!("{:?}", parse_month("2011-11")),
+			r#"Some(YearMonth("2011-11"))"#
+		);
+	}
+
+	#[test]
+	fn test_canonical_month() {
+		assert_eq!(
+			parse_month("2011-11").unwrap().to_canonical_string(),
+			"2011-11"
+		);
+		assert_eq!(
+			parse_month("0099-01").unwrap().to_canonical_string(),
+			"0099-01"
+		);
+	}
+
+	#[test]
+	fn test_canonical_date() {
+		assert_eq!(
+			parse_date("2011-11-18").unwrap().to_canonical_string(),
+			"2011-11-18"
+		);
+	}
+
+	#[test]
+	fn test_canonical_yearless_date() {
+		assert_eq!(
+			parse_yearless_date("--11-18")
+				.unwrap()
+				.to_canonical_string(),
+			"11-18"
+		);
+	}
+
+	#[test]
+	fn test_canonical_time() {
+		assert_eq!(parse_time("14:54").unwrap().to_canonical_string(), "14:54");
+		assert_eq!(
+			parse_time("14:54:00").unwrap().to_canonical_string(),
+			"14:54"
+		);
+		assert_eq!(
+			parse_time("14:54:39").unwrap().to_canonical_string(),
+			"14:54:39"
+		);
+		assert_eq!(
+			parse_time("14:54:39.900").unwrap().to_canonical_string(),
+			"14:54:39.9"
+		);
+		assert_eq!(
+			parse_time("14:54:00.001").unwrap().to_canonical_string(),
+			"14:54:00.001"
+		);
+	}
+
+	#[test]
+	fn test_canonical_local_datetime() {
+		assert_eq!(
+			parse_local_datetime("2011-11-18 14:54:00")
+				.unwrap()
+				.to_canonical_string(),
+			"2011-11-18T14:54"
+		);
+	}
+
+	#[test]
+	fn test_canonical_global_datetime() {
+		assert_eq!(
+			parse_global_datetime("2011-11-18T14:54:39.929-04:00")
+				.unwrap()
+				.to_canonical_string(),
+			"2011-11-18T18:54:39.929Z"
+		);
+	}
+
+	#[test]
+	fn test_canonical_timezone_offset() {
+		assert_eq!(
+			parse_timezone_offset("-0700")
+				.unwrap()
+				.to_canonical_string(),
+			"-07:00"
+		);
+		assert_eq!(
+			parse_timezone_offset("+00:00")
+				.unwrap()
+				.to_canonical_string(),
+			"Z"
+		);
+	}
+
+	#[test]
+	fn test_canonical_week() {
+		assert_eq!(
+			parse_week("2004-W53").unwrap().to_canonical_string(),
+			"2004-W53"
+		);
+		assert_eq!(
+			parse_week("2011-W01").unwrap().to_canonical_string(),
+			"2011-W01"
+		);
+	}
+
+	#[test]
+	fn test_canonical_duration() {
+		#[rustfmt::skip]
+		let cases = [
+			("P1DT2H", "P1DT2H"),
+			("1d 2h", "P1DT2H"),
+			("PT90M", "PT90M"),
+			("P1W", "P7D"),
+			("PT1.500S", "PT1.5S"),
+			("P0D", "PT0S"),
+			("1d 1s", "P1DT1S"),
+		];
+
+		for (input, expected) in cases {
+			assert_eq!(
+				parse_duration_components(input)
+					.unwrap()
+					.to_canonical_string(),
+				expected
+			);
+		}
+	}
+
+	#[test]
+	fn test_canonical_any_datetime() {
+		for s in [
+			"2011-11",
+			"2011-11-18",
+			"11-18",
+			"14:54:39.929",
+			"2011-11-18T14:54",
+			"2011-11-18T14:54Z",
+			"2011-W47",
+		] {
+			assert_eq!(parse_any_datetime(s).unwrap().to_canonical_string(), s);
+		}
+	}
+}
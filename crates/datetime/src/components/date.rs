@@ -1,6 +1,9 @@
+use crate::components::month::parse_month_component_impl;
 use crate::tokens::Token;
-use crate::{collect_day_and_validate, parse_format, parse_month_component};
+use crate::utils::ascii_char_at;
+use crate::{collect_day_and_validate, parse_format};
 use chrono::NaiveDate;
+use whatwg_infra::trim_ascii_whitespace;
 
 /// Parse a [proleptic-Gregorian date][proleptic-greg], in the format of `YYYY-MM-DD`
 ///
@@ -28,6 +31,84 @@ pub fn parse_date(s: &str) -> Option<NaiveDate> {
 	parse_format(s, parse_date_component)
 }
 
+/// Parse a string like [`parse_date`], after stripping leading and trailing ASCII whitespace
+#[inline]
+pub fn parse_date_trimmed(s: &str) -> Option<NaiveDate> {
+	parse_date(trim_ascii_whitespace(s))
+}
+
+/// Parse a date string from an HTML attribute value, such as the `datetime`
+/// attribute of a `<time>` element
+///
+/// Browsers strip leading and trailing ASCII whitespace from these attribute
+/// values before parsing them, so this behaves the same as [`parse_date_trimmed`].
+/// Whitespace within the date is still rejected.
+///
+/// # Examples
+/// ```
+/// use chrono::NaiveDate;
+/// use whatwg_datetime::parse_date_in_attribute;
+///
+/// // <time datetime=" 2011-11-18 ">
+/// assert_eq!(parse_date_in_attribute(" 2011-11-18 "), NaiveDate::from_ymd_opt(2011, 11, 18));
+/// assert_eq!(parse_date_in_attribute("2011- 11-18"), None);
+/// ```
+#[inline]
+pub fn parse_date_in_attribute(s: &str) -> Option<NaiveDate> {
+	parse_date_trimmed(s)
+}
+
+/// Parse a [proleptic-Gregorian date][proleptic-greg], also accepting
+/// the year zero and negative years
+///
+/// This behaves the same as [`parse_date`], except that the year may be
+/// `0000`, or may be prefixed with a U+002D HYPHEN-MINUS (`-`) for a year
+/// before it. Years are numbered astronomically, so `0000` is 1 BCE and
+/// `-0044` is 45 BCE. Note that this is not a valid date string per the
+/// WHATWG HTML Standard.
+///
+/// # Examples
+/// ```
+/// use chrono::NaiveDate;
+/// use whatwg_datetime::{parse_date, parse_date_proleptic};
+///
+/// assert_eq!(parse_date_proleptic("-0044-03-15"), NaiveDate::from_ymd_opt(-44, 3, 15));
+/// assert_eq!(parse_date_proleptic("0000-01-01"), NaiveDate::from_ymd_opt(0, 1, 1));
+/// assert_eq!(parse_date("0000-01-01"), None);
+/// ```
+///
+/// [proleptic-greg]: https://html.spec.whatwg.org/multipage/common-microsyntaxes.html#proleptic-gregorian-date
+#[inline]
+pub fn parse_date_proleptic(s: &str) -> Option<NaiveDate> {
+	parse_format(s, |s, position| {
+		parse_date_component_impl(s, position, true, Token::HYPHEN)
+	})
+}
+
+/// Parse a date string like [`parse_date`], using a different separator
+/// between the year, month, and day
+///
+/// This is useful for dates such as `2011/11/18`, which aren't valid date
+/// strings per the WHATWG HTML Standard. The separator must be an ASCII
+/// codepoint other than an ASCII digit, otherwise this always returns [`None`].
+///
+/// # Examples
+/// ```
+/// use chrono::NaiveDate;
+/// use whatwg_datetime::{parse_date, parse_date_lenient};
+///
+/// assert_eq!(parse_date_lenient("2011/11/18", '/'), NaiveDate::from_ymd_opt(2011, 11, 18));
+/// assert_eq!(parse_date_lenient("2011.11.18", '.'), NaiveDate::from_ymd_opt(2011, 11, 18));
+/// assert_eq!(parse_date_lenient("2011-11/18", '/'), None);
+/// assert_eq!(parse_date("2011/11/18"), None);
+/// ```
+#[inline]
+pub fn parse_date_lenient(s: &str, separator: char) -> Option<NaiveDate> {
+	parse_format(s, |s, position| {
+		parse_date_component_impl(s, position, false, separator)
+	})
+}
+
 /// Low-level function for parsing an individual date component at a given position
 ///
 /// This follows the rules for [parsing a date component][whatwg-html-parse],
@@ -50,12 +131,22 @@ pub fn parse_date(s: &str) -> Option<NaiveDate> {
 ///
 /// [whatwg-html-dates]: https://html.spec.whatwg.org/multipage/common-microsyntaxes.html#dates
 /// [whatwg-html-parse]: https://html.spec.whatwg.org/multipage/common-microsyntaxes.html#parse-a-date-component
+#[inline]
 pub fn parse_date_component(s: &str, position: &mut usize) -> Option<NaiveDate> {
-	let year_month = parse_month_component(s, position)?;
+	parse_date_component_impl(s, position, false, Token::HYPHEN)
+}
+
+fn parse_date_component_impl(
+	s: &str,
+	position: &mut usize,
+	proleptic: bool,
+	separator: char,
+) -> Option<NaiveDate> {
+	let year_month = parse_month_component_impl(s, position, proleptic, separator)?;
 	let year = year_month.year;
 	let month = year_month.month;
 
-	if *position > s.len() || s.chars().nth(*position) != Some(Token::HYPHEN) {
+	if ascii_char_at(s, *position) != Some(separator) {
 		return None;
 	} else {
 		*position += 1;
@@ -67,7 +158,14 @@ pub fn parse_date_component(s: &str, position: &mut usize) -> Option<NaiveDate>
 
 #[cfg(test)]
 mod tests {
-	use super::parse_date;
+	#[rustfmt::skip]
+	use super::{
+		parse_date,
+		parse_date_component,
+		parse_date_in_attribute,
+		parse_date_lenient,
+		parse_date_proleptic,
+	};
 	use chrono::NaiveDate;
 
 	#[test]
@@ -78,6 +176,83 @@ mod tests {
 		);
 	}
 
+	#[test]
+	fn test_parse_date_lenient_slash() {
+		assert_eq!(
+			parse_date_lenient("2011/11/18", '/'),
+			NaiveDate::from_ymd_opt(2011, 11, 18)
+		);
+		assert_eq!(parse_date_lenient("2011-11-18", '/'), None);
+		assert_eq!(parse_date_lenient("2011/11-18", '/'), None);
+	}
+
+	#[test]
+	fn test_parse_date_lenient_dot() {
+		assert_eq!(
+			parse_date_lenient("2011.11.18", '.'),
+			NaiveDate::from_ymd_opt(2011, 11, 18)
+		);
+		assert_eq!(parse_date_lenient("2007.02.29", '.'), None);
+	}
+
+	#[test]
+	fn test_parse_date_lenient_hyphen() {
+		assert_eq!(
+			parse_date_lenient("2011-11-18", '-'),
+			parse_date("2011-11-18")
+		);
+	}
+
+	#[test]
+	fn test_parse_date_lenient_fails_non_ascii_separator() {
+		assert_eq!(
+			parse_date_lenient("2011\u{2010}11\u{2010}18", '\u{2010}'),
+			None
+		);
+	}
+
+	#[test]
+	fn test_parse_date_fails_slash() {
+		assert_eq!(parse_date("2011/11/18"), None);
+	}
+
+	#[test]
+	fn test_parse_date_proleptic_negative_year() {
+		assert_eq!(
+			parse_date_proleptic("-0044-03-15"),
+			NaiveDate::from_ymd_opt(-44, 3, 15)
+		);
+	}
+
+	#[test]
+	fn test_parse_date_proleptic_year_zero() {
+		assert_eq!(
+			parse_date_proleptic("0000-01-01"),
+			NaiveDate::from_ymd_opt(0, 1, 1)
+		);
+		// Year 0 is a leap year in the proleptic Gregorian calendar
+		assert_eq!(
+			parse_date_proleptic("0000-02-29"),
+			NaiveDate::from_ymd_opt(0, 2, 29)
+		);
+	}
+
+	#[test]
+	fn test_parse_date_proleptic_positive_year() {
+		assert_eq!(
+			parse_date_proleptic("2011-11-18"),
+			NaiveDate::from_ymd_opt(2011, 11, 18)
+		);
+	}
+
+	#[test]
+	fn test_parse_date_proleptic_fails() {
+		assert_eq!(parse_date_proleptic("-044-03-15"), None);
+		assert_eq!(parse_date_proleptic("--0044-03-15"), None);
+		assert_eq!(parse_date_proleptic("+0044-03-15"), None);
+		assert_eq!(parse_date("-0044-03-15"), None);
+	}
+
 	#[test]
 	fn test_parse_date_leap_year() {
 		assert_eq!(
@@ -110,4 +285,46 @@ mod tests {
 	fn test_parse_date_fails_invalid_separator() {
 		assert_eq!(parse_date("2011-11/19"), None);
 	}
+
+	#[test]
+	fn test_parse_date_fails_multibyte_separator() {
+		assert_eq!(parse_date("2011\u{2010}11-18"), None);
+		assert_eq!(parse_date("2011-11\u{2010}18"), None);
+	}
+
+	#[test]
+	fn test_parse_date_fails_multibyte_trailing_content() {
+		assert_eq!(parse_date("2011-11-18\u{e9}"), None);
+		assert_eq!(parse_date("2011-11-\u{e9}"), None);
+	}
+
+	#[test]
+	fn test_parse_date_component_multibyte_prefix() {
+		let mut position = 3usize;
+		let parsed = parse_date_component("\u{65E5}2011-11-18", &mut position);
+
+		assert_eq!(parsed, NaiveDate::from_ymd_opt(2011, 11, 18));
+		assert_eq!(position, 13);
+
+		let mut position = 1usize;
+		let parsed = parse_date_component("\u{65E5}2011-11-18", &mut position);
+
+		assert_eq!(parsed, None);
+	}
+
+	#[test]
+	fn test_parse_date_in_attribute() {
+		let expected = NaiveDate::from_ymd_opt(2011, 11, 18);
+		assert_eq!(parse_date_in_attribute("2011-11-18"), expected);
+		assert_eq!(parse_date_in_attribute(" 2011-11-18 "), expected);
+		assert_eq!(parse_date_in_attribute("\t\n2011-11-18\r\x0C"), expected);
+	}
+
+	#[test]
+	fn test_parse_date_in_attribute_fails_embedded_whitespace() {
+		assert_eq!(parse_date_in_attribute("2011 -11-18"), None);
+		assert_eq!(parse_date_in_attribute(" 2011-11- 18 "), None);
+		assert_eq!(parse_date_in_attribute("2011-11-18\u{A0}"), None);
+		assert_eq!(parse_date_in_attribute("   "), None);
+	}
 }
@@ -0,0 +1,591 @@
+use crate::tokens::Token;
+use crate::utils::{ascii_char_at, collect_ascii_digits, skip_ascii_whitespace_at};
+use chrono::Duration;
+
+/// The components of a [duration][whatwg-html-durations], as they were written
+/// in a duration string, before being normalized into a single length of time.
+///
+/// Weeks are folded into days, and a fraction of a second is kept to
+/// millisecond precision. Other components are kept as-is, so `"PT90M"` has
+/// 90 minutes rather than 1 hour and 30 minutes.
+///
+/// # Examples
+/// ```
+/// use whatwg_datetime::parse_duration_components;
+///
+/// let components = parse_duration_components("P1DT2H").unwrap();
+/// assert_eq!(components.days(), 1);
+/// assert_eq!(components.hours(), 2);
+/// ```
+///
+/// [whatwg-html-durations]: https://html.spec.whatwg.org/multipage/common-microsyntaxes.html#durations
+#[derive(Clone, Copy, Default, PartialEq, Eq)]
+pub struct DurationComponents {
+	pub(crate) days: u32,
+	pub(crate) hours: u32,
+	pub(crate) minutes: u32,
+	pub(crate) seconds: u32,
+	pub(crate) milliseconds: u32,
+}
+
+impl DurationComponents {
+	pub(crate) const fn new(
+		days: u32,
+		hours: u32,
+		minutes: u32,
+		seconds: u32,
+		milliseconds: u32,
+	) -> Self {
+		Self {
+			days,
+			hours,
+			minutes,
+			seconds,
+			milliseconds,
+		}
+	}
+
+	/// The number of days, including any weeks as 7 days each
+	#[inline]
+	pub const fn days(&self) -> u32 {
+		self.days
+	}
+
+	/// The number of hours
+	#[inline]
+	pub const fn hours(&self) -> u32 {
+		self.hours
+	}
+
+	/// The number of minutes
+	#[inline]
+	pub const fn minutes(&self) -> u32 {
+		self.minutes
+	}
+
+	/// The number of whole seconds
+	#[inline]
+	pub const fn seconds(&self) -> u32 {
+		self.seconds
+	}
+
+	/// The number of milliseconds. This is a number from 0 to 999, inclusive.
+	#[inline]
+	pub const fn milliseconds(&self) -> u32 {
+		self.milliseconds
+	}
+
+	/// Normalizes the components into a single length of time.
+	///
+	/// # Examples
+	/// ```
+	/// use chrono::Duration;
+	/// use whatwg_datetime::parse_duration_components;
+	///
+	/// let components = parse_duration_components("PT90M").unwrap();
+	/// assert_eq!(components.to_duration(), Duration::minutes(90));
+	/// ```
+	pub fn to_duration(&self) -> Duration {
+		Duration::days(i64::from(self.days))
+			+ Duration::hours(i64::from(self.hours))
+			+ Duration::minutes(i64::from(self.minutes))
+			+ Duration::seconds(i64::from(self.seconds))
+			+ Duration::milliseconds(i64::from(self.milliseconds))
+	}
+
+	/// The total length of time of the components, in milliseconds.
+	///
+	/// # Examples
+	/// ```
+	/// use whatwg_datetime::parse_duration_components;
+	///
+	/// let components = parse_duration_components("PT1M30.5S").unwrap();
+	/// assert_eq!(components.total_milliseconds(), 90_500);
+	/// ```
+	pub const fn total_milliseconds(&self) -> i64 {
+		self.days as i64 * MILLISECONDS_PER_DAY
+			+ self.hours as i64 * MILLISECONDS_PER_HOUR
+			+ self.minutes as i64 * MILLISECONDS_PER_MINUTE
+			+ self.seconds as i64 * MILLISECONDS_PER_SECOND
+			+ self.milliseconds as i64
+	}
+
+	/// Adds two sets of components together, normalizing the result so that
+	/// each component carries over into the next largest one.
+	///
+	/// For example, 90 seconds becomes 1 minute and 30 seconds, and 24 hours
+	/// becomes 1 day. This returns [`None`] if the number of days overflows.
+	///
+	/// # Examples
+	/// ```
+	/// use whatwg_datetime::parse_duration_components;
+	///
+	/// let a = parse_duration_components("PT90S").unwrap();
+	/// let b = parse_duration_components("PT30S").unwrap();
+	/// let sum = a.checked_add(&b).unwrap();
+	/// assert_eq!(sum.minutes(), 2);
+	/// assert_eq!(sum.seconds(), 0);
+	/// ```
+	pub fn checked_add(&self, other: &DurationComponents) -> Option<DurationComponents> {
+		let total = self.total_milliseconds() + other.total_milliseconds();
+		let days = u32::try_from(total / MILLISECONDS_PER_DAY).ok()?;
+		let rest = total % MILLISECONDS_PER_DAY;
+
+		Some(Self::new(
+			days,
+			(rest / MILLISECONDS_PER_HOUR) as u32,
+			(rest % MILLISECONDS_PER_HOUR / MILLISECONDS_PER_MINUTE) as u32,
+			(rest % MILLISECONDS_PER_MINUTE / MILLISECONDS_PER_SECOND) as u32,
+			(rest % MILLISECONDS_PER_SECOND) as u32,
+		))
+	}
+}
+
+const MILLISECONDS_PER_SECOND: i64 = 1000;
+const MILLISECONDS_PER_MINUTE: i64 = 60 * MILLISECONDS_PER_SECOND;
+const MILLISECONDS_PER_HOUR: i64 = 60 * MILLISECONDS_PER_MINUTE;
+const MILLISECONDS_PER_DAY: i64 = 24 * MILLISECONDS_PER_HOUR;
+
+/// A unit of a [`DurationComponents`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum DurationUnit {
+	/// The days component, see [`DurationComponents::days()`]
+	Days,
+	/// The hours component, see [`DurationComponents::hours()`]
+	Hours,
+	/// The minutes component, see [`DurationComponents::minutes()`]
+	Minutes,
+	/// The seconds component, see [`DurationComponents::seconds()`]
+	Seconds,
+	/// The milliseconds component, see [`DurationComponents::milliseconds()`]
+	Milliseconds,
+}
+
+impl IntoIterator for DurationComponents {
+	type Item = (DurationUnit, u32);
+	type IntoIter = core::array::IntoIter<(DurationUnit, u32), 5>;
+
+	/// Iterates over each component with its unit, from the largest unit
+	/// to the smallest unit.
+	///
+	/// # Examples
+	/// ```
+	/// use whatwg_datetime::{parse_duration_components, DurationUnit};
+	///
+	/// let components = parse_duration_components("1d 2h").unwrap();
+	/// let nonzero: Vec<_> = components.into_iter().filter(|(_, n)| *n != 0).collect();
+	///
+	/// assert_eq!(nonzero, vec![(DurationUnit::Days, 1), (DurationUnit::Hours, 2)]);
+	/// ```
+	fn into_iter(self) -> Self::IntoIter {
+		[
+			(DurationUnit::Days, self.days),
+			(DurationUnit::Hours, self.hours),
+			(DurationUnit::Minutes, self.minutes),
+			(DurationUnit::Seconds, self.seconds),
+			(DurationUnit::Milliseconds, self.milliseconds),
+		]
+		.into_iter()
+	}
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum MDisambiguator {
+	Months,
+	Minutes,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Unit {
+	Years,
+	Months,
+	Weeks,
+	Days,
+	Hours,
+	Minutes,
+	Seconds,
+}
+
+/// Parse a [duration][whatwg-html-durations], preserving each of its components
+///
+/// This follows the rules for [parsing a duration string][whatwg-html-parse]
+/// per [WHATWG HTML Standard § 2.3.5.9 Durations][whatwg-html-durations].
+/// Both the ISO 8601 form (e.g `"P1DT2H"`) and the alternate form
+/// (e.g `"1d 2h"`) are accepted. Durations with a number of months or years
+/// other than zero are rejected, since they don't have a fixed length.
+///
+/// # Examples
+/// ```
+/// use whatwg_datetime::parse_duration_components;
+///
+/// assert_eq!(
+///     parse_duration_components("P1DT2H"),
+///     parse_duration_components("1d 2h")
+/// );
+/// assert_eq!(parse_duration_components("P1Y"), None);
+/// ```
+///
+/// [whatwg-html-durations]: https://html.spec.whatwg.org/multipage/common-microsyntaxes.html#durations
+/// [whatwg-html-parse]: https://html.spec.whatwg.org/multipage/common-microsyntaxes.html#parse-a-duration-string
+pub fn parse_duration_components(s: &str) -> Option<DurationComponents> {
+	// Step 1, 2, 3
+	let mut position = 0usize;
+	let mut months = 0u32;
+	let mut components = DurationComponents::new(0, 0, 0, 0, 0);
+	let mut component_count = 0usize;
+	let mut m_disambiguator = MDisambiguator::Minutes;
+
+	// Step 4, 5
+	skip_ascii_whitespace_at(s, &mut position);
+	let mut next_char = ascii_char_at(s, position)?;
+
+	// Step 6
+	if next_char.to_ascii_uppercase() == Token::ABBR_PERIOD {
+		position += 1;
+		m_disambiguator = MDisambiguator::Months;
+		skip_ascii_whitespace_at(s, &mut position);
+	}
+
+	// Step 7
+	while position < s.len() {
+		let c = ascii_char_at(s, position)?;
+		if c.to_ascii_uppercase() == Token::T {
+			position += 1;
+			m_disambiguator = MDisambiguator::Minutes;
+			skip_ascii_whitespace_at(s, &mut position);
+			continue;
+		}
+
+		let n = if c == Token::DOT {
+			0u32
+		} else if c.is_ascii_digit() {
+			collect_ascii_digits(s, &mut position).parse::<u32>().ok()?
+		} else {
+			return None;
+		};
+
+		next_char = ascii_char_at(s, position)?;
+		position += 1;
+
+		let mut milliseconds = 0u32;
+		let units = if next_char == Token::DOT {
+			let fraction = collect_ascii_digits(s, &mut position);
+			if fraction.is_empty() {
+				return None;
+			}
+			milliseconds = parse_fraction_as_milliseconds(&fraction);
+
+			skip_ascii_whitespace_at(s, &mut position);
+			next_char = ascii_char_at(s, position)?;
+			position += 1;
+			if next_char.to_ascii_uppercase() != Token::ABBR_SEC {
+				return None;
+			}
+
+			Unit::Seconds
+		} else {
+			if next_char.is_ascii_whitespace() {
+				skip_ascii_whitespace_at(s, &mut position);
+				next_char = ascii_char_at(s, position)?;
+				position += 1;
+			}
+
+			match (next_char.to_ascii_uppercase(), m_disambiguator) {
+				(Token::ABBR_YEAR, _) => {
+					m_disambiguator = MDisambiguator::Months;
+					Unit::Years
+				}
+				(Token::ABBR_MIN, MDisambiguator::Months) => Unit::Months,
+				(Token::ABBR_WEEK, _) => {
+					m_disambiguator = MDisambiguator::Minutes;
+					Unit::Weeks
+				}
+				(Token::ABBR_DAY, _) => {
+					m_disambiguator = MDisambiguator::Minutes;
+					Unit::Days
+				}
+				(Token::ABBR_HOUR, _) => {
+					m_disambiguator = MDisambiguator::Minutes;
+					Unit::Hours
+				}
+				(Token::ABBR_MIN, MDisambiguator::Minutes) => Unit::Minutes,
+				(Token::ABBR_SEC, _) => Unit::Seconds,
+				_ => return None,
+			}
+		};
+
+		component_count += 1;
+		match units {
+			Unit::Years => months = months.checked_add(n.checked_mul(12)?)?,
+			Unit::Months => months = months.checked_add(n)?,
+			Unit::Weeks => {
+				components.days = components.days.checked_add(n.checked_mul(7)?)?
+			}
+			Unit::Days => components.days = components.days.checked_add(n)?,
+			Unit::Hours => components.hours = components.hours.checked_add(n)?,
+			Unit::Minutes => components.minutes = components.minutes.checked_add(n)?,
+			Unit::Seconds => {
+				let total_milliseconds = components.milliseconds + milliseconds;
+				components.seconds = components
+					.seconds
+					.checked_add(n)?
+					.checked_add(total_milliseconds / 1000)?;
+				components.milliseconds = total_milliseconds % 1000;
+			}
+		}
+
+		skip_ascii_whitespace_at(s, &mut position);
+	}
+
+	// Step 8, 9
+	if component_count == 0 || months != 0 {
+		return None;
+	}
+
+	// Step 10
+	Some(components)
+}
+
+/// Parse a [duration][whatwg-html-durations]
+///
+/// This follows the rules for [parsing a duration string][whatwg-html-parse]
+/// per [WHATWG HTML Standard § 2.3.5.9 Durations][whatwg-html-durations].
+/// See [`parse_duration_components()`] to keep each component as written.
+///
+/// # Examples
+/// ```
+/// use chrono::Duration;
+/// use whatwg_datetime::parse_duration;
+///
+/// assert_eq!(
+///     parse_duration("PT1H30M"),
+///     Some(Duration::hours(1) + Duration::minutes(30))
+/// );
+/// assert_eq!(parse_duration("4w 3s"), Some(Duration::weeks(4) + Duration::seconds(3)));
+/// ```
+///
+/// [whatwg-html-durations]: https://html.spec.whatwg.org/multipage/common-microsyntaxes.html#durations
+/// [whatwg-html-parse]: https://html.spec.whatwg.org/multipage/common-microsyntaxes.html#parse-a-duration-string
+#[inline]
+pub fn parse_duration(s: &str) -> Option<Duration> {
+	parse_duration_components(s).map(|components| components.to_duration())
+}
+
+/// Interprets a string of ASCII digits as the digits after a decimal point,
+/// truncated to millisecond precision
+fn parse_fraction_as_milliseconds(fraction: &str) -> u32 {
+	fraction.bytes()
+		.chain(core::iter::repeat(b'0'))
+		.take(3)
+		.fold(0u32, |acc, digit| acc * 10 + u32::from(digit - b'0'))
+}
+
+#[cfg(test)]
+mod tests {
+	#[rustfmt::skip]
+	use super::{
+		parse_duration,
+		parse_duration_components,
+		DurationComponents,
+		DurationUnit,
+	};
+	use chrono::Duration;
+
+	#[test]
+	fn test_parse_duration_components_iso8601() {
+		assert_eq!(
+			parse_duration_components("P1DT2H"),
+			Some(DurationComponents::new(1, 2, 0, 0, 0))
+		);
+	}
+
+	#[test]
+	fn test_parse_duration_components_alternate() {
+		assert_eq!(
+			parse_duration_components("1d 2h"),
+			Some(DurationComponents::new(1, 2, 0, 0, 0))
+		);
+		assert_eq!(
+			parse_duration_components("1d 2h"),
+			parse_duration_components("P1DT2H")
+		);
+	}
+
+	#[test]
+	fn test_parse_duration_components_all_units() {
+		assert_eq!(
+			parse_duration_components("P1W2DT3H4M5.678S"),
+			Some(DurationComponents::new(9, 3, 4, 5, 678))
+		);
+		assert_eq!(
+			parse_duration_components("1w 2d 3h 4m 5.678s"),
+			Some(DurationComponents::new(9, 3, 4, 5, 678))
+		);
+	}
+
+	#[test]
+	fn test_parse_duration_components_not_normalized() {
+		assert_eq!(
+			parse_duration_components("PT90M"),
+			Some(DurationComponents::new(0, 0, 90, 0, 0))
+		);
+	}
+
+	#[test]
+	fn test_parse_duration_components_case_insensitive() {
+		assert_eq!(
+			parse_duration_components("p1dt2h"),
+			Some(DurationComponents::new(1, 2, 0, 0, 0))
+		);
+	}
+
+	#[test]
+	fn test_parse_duration_components_whitespace() {
+		assert_eq!(
+			parse_duration_components(" 1 d  2 h "),
+			Some(DurationComponents::new(1, 2, 0, 0, 0))
+		);
+	}
+
+	#[test]
+	fn test_parse_duration_components_fraction() {
+		assert_eq!(
+			parse_duration_components("PT.5S"),
+			Some(DurationComponents::new(0, 0, 0, 0, 500))
+		);
+		assert_eq!(
+			parse_duration_components("0.05s"),
+			Some(DurationComponents::new(0, 0, 0, 0, 50))
+		);
+		assert_eq!(
+			parse_duration_components("1.2345s"),
+			Some(DurationComponents::new(0, 0, 0, 1, 234))
+		);
+		assert_eq!(
+			parse_duration_components("0.5s 0.6s"),
+			Some(DurationComponents::new(0, 0, 0, 1, 100))
+		);
+	}
+
+	#[test]
+	fn test_parse_duration_components_zero_months() {
+		assert_eq!(
+			parse_duration_components("P0Y0M1D"),
+			Some(DurationComponents::new(1, 0, 0, 0, 0))
+		);
+	}
+
+	#[test]
+	fn test_parse_duration_components_fails_months() {
+		assert_eq!(parse_duration_components("P1Y"), None);
+		assert_eq!(parse_duration_components("P1M"), None);
+	}
+
+	#[test]
+	fn test_parse_duration_components_fails_empty() {
+		assert_eq!(parse_duration_components(""), None);
+		assert_eq!(parse_duration_components("P"), None);
+		assert_eq!(parse_duration_components("PT"), None);
+	}
+
+	#[test]
+	fn test_parse_duration_components_fails_invalid() {
+		assert_eq!(parse_duration_components("1"), None);
+		assert_eq!(parse_duration_components("1x"), None);
+		assert_eq!(parse_duration_components("1.5h"), None);
+		assert_eq!(parse_duration_components("1.s"), None);
+		assert_eq!(parse_duration_components("-1d"), None);
+		assert_eq!(parse_duration_components("1d\u{e9}"), None);
+	}
+
+	#[test]
+	fn test_parse_duration_components_fails_overflow() {
+		assert_eq!(parse_duration_components("99999999999d"), None);
+		assert_eq!(parse_duration_components("4294967295d 1d"), None);
+	}
+
+	#[test]
+	fn test_duration_components_into_iter() {
+		let components = DurationComponents::new(1, 2, 3, 4, 5);
+		let collected: Vec<_> = components.into_iter().collect();
+
+		assert_eq!(
+			collected,
+			vec![
+				(DurationUnit::Days, 1),
+				(DurationUnit::Hours, 2),
+				(DurationUnit::Minutes, 3),
+				(DurationUnit::Seconds, 4),
+				(DurationUnit::Milliseconds, 5),
+			]
+		);
+	}
+
+	#[test]
+	fn test_parse_duration() {
+		assert_eq!(
+			parse_duration("P1DT2H"),
+			Some(Duration::days(1) + Duration::hours(2))
+		);
+		assert_eq!(
+			parse_duration("PT1M30.5S"),
+			Some(Duration::milliseconds(90_500))
+		);
+	}
+
+	#[test]
+	fn test_duration_components_total_milliseconds() {
+		assert_eq!(
+			DurationComponents::new(0, 0, 0, 0, 0).total_milliseconds(),
+			0
+		);
+		assert_eq!(
+			DurationComponents::new(1, 2, 3, 4, 5).total_milliseconds(),
+			93_784_005
+		);
+		assert_eq!(
+			DurationComponents::new(u32::MAX, u32::MAX, u32::MAX, u32::MAX, 999)
+				.total_milliseconds(),
+			Duration::days(i64::from(u32::MAX)).num_milliseconds()
+				+ Duration::hours(i64::from(u32::MAX)).num_milliseconds()
+				+ Duration::minutes(i64::from(u32::MAX)).num_milliseconds()
+				+ Duration::seconds(i64::from(u32::MAX)).num_milliseconds()
+				+ 999
+		);
+	}
+
+	#[test]
+	fn test_duration_components_checked_add() {
+		let a = parse_duration_components("PT90S").unwrap();
+		let b = parse_duration_components("PT30S").unwrap();
+		assert_eq!(
+			a.checked_add(&b),
+			Some(DurationComponents::new(0, 0, 2, 0, 0))
+		);
+	}
+
+	#[test]
+	fn test_duration_components_checked_add_normalizes() {
+		let a = parse_duration_components("PT23H59M59.5S").unwrap();
+		let b = parse_duration_components("PT0.75S").unwrap();
+		assert_eq!(
+			a.checked_add(&b),
+			Some(DurationComponents::new(1, 0, 0, 0, 250))
+		);
+
+		let a = parse_duration_components("PT90M").unwrap();
+		let zero = DurationComponents::default();
+		assert_eq!(
+			a.checked_add(&zero),
+			Some(DurationComponents::new(0, 1, 30, 0, 0))
+		);
+	}
+
+	#[test]
+	fn test_duration_components_checked_add_overflow() {
+		let max = DurationComponents::new(u32::MAX, 0, 0, 0, 0);
+		let one = DurationComponents::new(1, 0, 0, 0, 0);
+		assert_eq!(max.checked_add(&one), None);
+		assert_eq!(max.checked_add(&DurationComponents::default()), Some(max));
+	}
+}
@@ -0,0 +1,88 @@
+use core::fmt;
+
+/// An error which can be returned when parsing a datetime microsyntax
+///
+/// # Examples
+/// ```
+/// use whatwg_datetime::{parse_exact_tracked, parse_month_component, DatetimeParseError};
+///
+/// assert_eq!(
+///     parse_exact_tracked("2011-11garbage", parse_month_component),
+///     Err(DatetimeParseError::TrailingGarbage { position: 7 })
+/// );
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum DatetimeParseError {
+	/// The input does not match the expected format
+	Invalid {
+		/// The position at which parsing stopped
+		position: usize,
+	},
+	/// The input was parsed successfully, but was followed by leftover characters
+	TrailingGarbage {
+		/// The byte position of the first leftover code point
+		position: usize,
+	},
+}
+
+impl fmt::Display for DatetimeParseError {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		match self {
+			Self::Invalid { position } => {
+				write!(f, "invalid datetime string at position {}", position)
+			}
+			Self::TrailingGarbage { position } => {
+				write!(f, "unexpected trailing characters at position {}", position)
+			}
+		}
+	}
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for DatetimeParseError {}
+
+#[cfg(test)]
+mod tests {
+	use super::DatetimeParseError;
+
+	#[test]
+	fn test_display_invalid() {
+		let error = DatetimeParseError::Invalid { position: 7 };
+		assert_eq!(error.to_string(), "invalid datetime string at position 7");
+	}
+
+	#[test]
+	fn test_display_trailing_garbage() {
+		let error = DatetimeParseError::TrailingGarbage { position: 12 };
+		assert!(error.to_string().contains("position 12"));
+	}
+
+	#[cfg(feature = "std")]
+	#[test]
+	fn test_error_trait() {
+		let error: Box<dyn std::error::Error> =
+			Box::new(DatetimeParseError::TrailingGarbage { position: 7 });
+		assert_eq!(
+			error.to_string(),
+			"unexpected trailing characters at position 7"
+		);
+	}
+
+	#[cfg(feature = "std")]
+	#[test]
+	fn test_error_trait_question_mark() {
+		use crate::{parse_exact_tracked, parse_month_component, YearMonth};
+		use std::error::Error;
+
+		fn parse(s: &str) -> Result<YearMonth, Box<dyn Error>> {
+			Ok(parse_exact_tracked(s, parse_month_component)?)
+		}
+
+		assert_eq!(parse("2011-11").ok(), YearMonth::new_opt(2011, 11));
+		assert_eq!(
+			parse("2011-11garbage").unwrap_err().to_string(),
+			"unexpected trailing characters at position 7"
+		);
+	}
+}
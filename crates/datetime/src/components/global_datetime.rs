@@ -1,6 +1,6 @@
-use crate::tokens::Token;
-use crate::{parse_date_component, parse_time_component, parse_timezone_offset_component};
-use chrono::{DateTime, Duration, NaiveDateTime, TimeZone, Utc};
+use crate::{DelimiterPolicy, Parser, TimeZoneOffset};
+use chrono::{DateTime, Duration, FixedOffset, NaiveDateTime, TimeZone, Utc};
+use whatwg_infra::trim_ascii_whitespace;
 
 /// Parse a [proleptic-Gregorian date][proleptic-greg] consisting
 /// of a date, time, and an optional time-zone offset
@@ -28,42 +28,168 @@ use chrono::{DateTime, Duration, NaiveDateTime, TimeZone, Utc};
 /// [proleptic-greg]: https://html.spec.whatwg.org/multipage/common-microsyntaxes.html#proleptic-gregorian-date
 /// [whatwg-html-global-datetime]: https://html.spec.whatwg.org/multipage/common-microsyntaxes.html#global-dates-and-times
 /// [whatwg-html-parse]: https://html.spec.whatwg.org/multipage/common-microsyntaxes.html#parse-a-global-date-and-time-string
+#[inline]
 pub fn parse_global_datetime(s: &str) -> Option<DateTime<Utc>> {
-	let mut position = 0usize;
-	let date = parse_date_component(s, &mut position)?;
+	parse_global_datetime_with(s, DelimiterPolicy::Either)
+}
 
-	let last_char = s.chars().nth(position);
-	if position > s.len() || !matches!(last_char, Some(Token::T) | Some(Token::SPACE)) {
-		return None;
-	} else {
-		position += 1;
-	}
+/// Parse a string like [`parse_global_datetime`], after stripping leading and trailing ASCII whitespace
+#[inline]
+pub fn parse_global_datetime_trimmed(s: &str) -> Option<DateTime<Utc>> {
+	parse_global_datetime(trim_ascii_whitespace(s))
+}
 
-	let time = parse_time_component(s, &mut position)?;
-	if position > s.len() {
-		return None;
-	}
+/// Parse a global datetime string, restricting which delimiters are
+/// accepted between the date and the time
+///
+/// This behaves the same as [`parse_global_datetime`], except that the
+/// delimiter must be accepted by the given [`DelimiterPolicy`].
+///
+/// # Examples
+/// ```
+/// use whatwg_datetime::{parse_global_datetime_with, DelimiterPolicy};
+///
+/// assert!(parse_global_datetime_with("2011-11-18T14:54Z", DelimiterPolicy::TOnly).is_some());
+/// assert!(parse_global_datetime_with("2011-11-18 14:54Z", DelimiterPolicy::TOnly).is_none());
+/// ```
+pub fn parse_global_datetime_with(s: &str, policy: DelimiterPolicy) -> Option<DateTime<Utc>> {
+	parse_global_datetime_and_offset(s, policy, false).map(|(datetime, _)| datetime)
+}
 
-	let timezone_offset = parse_timezone_offset_component(s, &mut position)?;
-	if position < s.len() {
-		return None;
-	}
+/// Parse a global datetime string, also accepting a lowercase delimiter
+/// and a lowercase UTC designator
+///
+/// This behaves the same as [`parse_global_datetime`], except that the
+/// delimiter may also be a U+0074 LATIN SMALL LETTER T, and the time-zone
+/// offset may also be a U+007A LATIN SMALL LETTER Z, which some producers emit.
+/// Note that this is not a valid global date and time string per the
+/// WHATWG HTML Standard.
+///
+/// # Examples
+/// ```
+/// use whatwg_datetime::{parse_global_datetime, parse_global_datetime_lenient};
+///
+/// assert_eq!(
+///     parse_global_datetime_lenient("2011-11-18t14:54z"),
+///     parse_global_datetime("2011-11-18T14:54Z")
+/// );
+/// assert_eq!(parse_global_datetime("2011-11-18t14:54z"), None);
+/// ```
+pub fn parse_global_datetime_lenient(s: &str) -> Option<DateTime<Utc>> {
+	parse_global_datetime_and_offset(s, DelimiterPolicy::Either, true)
+		.map(|(datetime, _)| datetime)
+}
+
+/// Parse a global datetime string, also returning the time-zone offset
+/// that was applied when converting it to UTC, in signed minutes
+///
+/// This behaves the same as [`parse_global_datetime`], and is useful for
+/// displaying the original offset without needing to parse the string again.
+///
+/// # Examples
+/// ```
+/// use chrono::{NaiveDate, NaiveDateTime, NaiveTime, TimeZone, Utc};
+/// use whatwg_datetime::parse_global_datetime_offset_minutes;
+///
+/// assert_eq!(
+///     parse_global_datetime_offset_minutes("2011-11-18T14:54-07:00"),
+///     Some((
+///         Utc.from_utc_datetime(&NaiveDateTime::new(
+///             NaiveDate::from_ymd_opt(2011, 11, 18).unwrap(),
+///             NaiveTime::from_hms_opt(21, 54, 0).unwrap(),
+///         )),
+///         -420
+///     ))
+/// );
+/// ```
+pub fn parse_global_datetime_offset_minutes(s: &str) -> Option<(DateTime<Utc>, i32)> {
+	parse_global_datetime_and_offset(s, DelimiterPolicy::Either, false)
+		.map(|(datetime, offset)| (datetime, offset.total_minutes()))
+}
+
+/// Interprets a date and time as the local time in the given time-zone offset
+///
+/// This returns `None` if the offset can't be represented by a
+/// [`chrono::FixedOffset`], or if the resulting datetime is out of range.
+///
+/// # Examples
+/// ```
+/// use chrono::{NaiveDate, NaiveDateTime, NaiveTime};
+/// use whatwg_datetime::{attach_offset, TimeZoneOffset};
+///
+/// let dt = NaiveDateTime::new(
+///     NaiveDate::from_ymd_opt(2011, 11, 18).unwrap(),
+///     NaiveTime::from_hms_opt(14, 54, 0).unwrap(),
+/// );
+/// let attached = attach_offset(dt, TimeZoneOffset::new_opt(-7, 0).unwrap()).unwrap();
+/// assert_eq!(attached.to_rfc3339(), "2011-11-18T14:54:00-07:00");
+/// ```
+pub fn attach_offset(dt: NaiveDateTime, tz: TimeZoneOffset) -> Option<DateTime<FixedOffset>> {
+	FixedOffset::east_opt(tz.total_minutes() * 60)?
+		.from_local_datetime(&dt)
+		.single()
+}
+
+fn parse_global_datetime_and_offset(
+	s: &str,
+	policy: DelimiterPolicy,
+	lenient: bool,
+) -> Option<(DateTime<Utc>, TimeZoneOffset)> {
+	let mut p = Parser::new(s);
+	let date = p.date()?;
+	p.delimiter_impl(policy, lenient)?;
+	let time = p.time()?;
+	let timezone_offset = p.timezone_offset_impl(lenient)?;
+	p.ensure_end()?;
 
-	let timezone_offset_as_duration =
-		Duration::minutes(timezone_offset.minute as i64 + timezone_offset.hour as i64 * 60);
 	let naive_datetime = NaiveDateTime::new(
 		date,
-		time.overflowing_sub_signed(timezone_offset_as_duration).0,
+		time.overflowing_sub_signed(Duration::from(timezone_offset))
+			.0,
 	);
 
-	Some(Utc.from_utc_datetime(&naive_datetime))
+	Some((Utc.from_utc_datetime(&naive_datetime), timezone_offset))
 }
 
 #[cfg(test)]
 mod tests {
-	use super::parse_global_datetime;
+	#[rustfmt::skip]
+	use super::{
+		attach_offset,
+		parse_global_datetime,
+		parse_global_datetime_lenient,
+		parse_global_datetime_offset_minutes,
+		parse_global_datetime_with,
+	};
+	use crate::{DelimiterPolicy, TimeZoneOffset};
 	use chrono::{NaiveDate, NaiveDateTime, NaiveTime, TimeZone, Utc};
 
+	#[test]
+	fn test_parse_global_datetime_lenient_lowercase() {
+		let expected = Some(Utc.from_utc_datetime(&NaiveDateTime::new(
+			NaiveDate::from_ymd_opt(2011, 11, 18).unwrap(),
+			NaiveTime::from_hms_opt(14, 54, 0).unwrap(),
+		)));
+
+		assert_eq!(parse_global_datetime_lenient("2011-11-18t14:54z"), expected);
+		assert_eq!(parse_global_datetime_lenient("2011-11-18T14:54z"), expected);
+		assert_eq!(parse_global_datetime_lenient("2011-11-18t14:54Z"), expected);
+		assert_eq!(parse_global_datetime_lenient("2011-11-18 14:54Z"), expected);
+	}
+
+	#[test]
+	fn test_parse_global_datetime_fails_lowercase() {
+		assert_eq!(parse_global_datetime("2011-11-18t14:54z"), None);
+		assert_eq!(parse_global_datetime("2011-11-18T14:54z"), None);
+		assert_eq!(parse_global_datetime("2011-11-18t14:54Z"), None);
+	}
+
+	#[test]
+	fn test_parse_global_datetime_lenient_fails_invalid() {
+		assert_eq!(parse_global_datetime_lenient("2011-11-18x14:54Z"), None);
+		assert_eq!(parse_global_datetime_lenient("2011-11-18T14:54y"), None);
+	}
+
 	#[test]
 	fn test_parse_global_datetime_t_hm() {
 		assert_eq!(
@@ -170,4 +296,93 @@ mod tests {
 	fn test_parse_global_datetime_fails_invalid_timezone_offset_2() {
 		assert_eq!(parse_global_datetime("1456-02-24T11:17C"), None);
 	}
+
+	#[test]
+	fn test_parse_global_datetime_with_t_only_fails_space() {
+		let parsed =
+			parse_global_datetime_with("2004-12-31 12:31Z", DelimiterPolicy::TOnly);
+		assert_eq!(parsed, None);
+	}
+
+	#[test]
+	fn test_parse_global_datetime_with_space_only() {
+		let parsed =
+			parse_global_datetime_with("2004-12-31 12:31Z", DelimiterPolicy::SpaceOnly);
+		assert_eq!(parsed, parse_global_datetime("2004-12-31 12:31Z"));
+		assert!(parsed.is_some());
+	}
+
+	#[test]
+	fn test_parse_global_datetime_with_space_only_fails_t() {
+		let parsed =
+			parse_global_datetime_with("2004-12-31T12:31Z", DelimiterPolicy::SpaceOnly);
+		assert_eq!(parsed, None);
+	}
+
+	#[test]
+	fn test_parse_global_datetime_offset_minutes_positive() {
+		assert_eq!(
+			parse_global_datetime_offset_minutes("2011-11-18T14:54+05:30"),
+			Some((
+				Utc.from_utc_datetime(&NaiveDateTime::new(
+					NaiveDate::from_ymd_opt(2011, 11, 18).unwrap(),
+					NaiveTime::from_hms_opt(9, 24, 0).unwrap(),
+				)),
+				330
+			))
+		);
+	}
+
+	#[test]
+	fn test_parse_global_datetime_offset_minutes_negative() {
+		let parsed = parse_global_datetime_offset_minutes("2011-11-18T14:54-07:00");
+		assert_eq!(parsed.map(|(_, offset)| offset), Some(-420));
+	}
+
+	#[test]
+	fn test_parse_global_datetime_offset_minutes_z() {
+		let parsed = parse_global_datetime_offset_minutes("2011-11-18T14:54Z");
+		assert_eq!(
+			parsed,
+			parse_global_datetime("2011-11-18T14:54Z").map(|datetime| (datetime, 0))
+		);
+	}
+
+	#[test]
+	fn test_parse_global_datetime_offset_minutes_fails() {
+		assert_eq!(
+			parse_global_datetime_offset_minutes("2011-11-18T14:54C"),
+			None
+		);
+	}
+
+	#[test]
+	fn test_attach_offset_negative() {
+		let dt = NaiveDateTime::new(
+			NaiveDate::from_ymd_opt(2011, 11, 18).unwrap(),
+			NaiveTime::from_hms_opt(14, 54, 0).unwrap(),
+		);
+		let attached = attach_offset(dt, TimeZoneOffset::new_opt(-7, 0).unwrap()).unwrap();
+
+		assert_eq!(attached.naive_local(), dt);
+		assert_eq!(attached.offset().local_minus_utc(), -7 * 3600);
+		assert_eq!(
+			attached.with_timezone(&Utc),
+			Utc.from_utc_datetime(&(dt + chrono::Duration::hours(7)))
+		);
+	}
+
+	#[test]
+	fn test_attach_offset_matches_global_datetime() {
+		let dt = NaiveDateTime::new(
+			NaiveDate::from_ymd_opt(2011, 11, 18).unwrap(),
+			NaiveTime::from_hms_opt(14, 54, 0).unwrap(),
+		);
+		let attached = attach_offset(dt, TimeZoneOffset::new_opt(-7, 0).unwrap()).unwrap();
+
+		assert_eq!(
+			Some(attached.with_timezone(&Utc)),
+			parse_global_datetime("2011-11-18T14:54-07:00")
+		);
+	}
 }
@@ -1,6 +1,44 @@
 use crate::tokens::Token;
-use crate::{parse_date_component, parse_time_component};
-use chrono::NaiveDateTime;
+use crate::{is_normalized_time_string, parse_date, parse_time, Parser};
+use chrono::{NaiveDate, NaiveDateTime, NaiveTime};
+use whatwg_infra::trim_ascii_whitespace;
+
+/// Which delimiters are accepted between the date and the time of a
+/// local or global datetime string
+///
+/// The WHATWG HTML Standard allows either a U+0054 LATIN CAPITAL LETTER T
+/// or a U+0020 SPACE; a [normalized][whatwg-html-normalized] string always uses `T`.
+///
+/// # Examples
+/// ```
+/// use whatwg_datetime::{parse_local_datetime_with, DelimiterPolicy};
+///
+/// assert!(parse_local_datetime_with("2011-11-18T14:54", DelimiterPolicy::TOnly).is_some());
+/// assert!(parse_local_datetime_with("2011-11-18 14:54", DelimiterPolicy::TOnly).is_none());
+/// ```
+///
+/// [whatwg-html-normalized]: https://html.spec.whatwg.org/multipage/common-microsyntaxes.html#valid-normalised-local-date-and-time-string
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum DelimiterPolicy {
+	/// Only accept a U+0054 LATIN CAPITAL LETTER T
+	TOnly,
+	/// Only accept a U+0020 SPACE
+	SpaceOnly,
+	/// Accept either a U+0054 LATIN CAPITAL LETTER T or a U+0020 SPACE
+	#[default]
+	Either,
+}
+
+impl DelimiterPolicy {
+	#[inline]
+	pub(crate) fn accepts(&self, c: char) -> bool {
+		match self {
+			DelimiterPolicy::TOnly => c == Token::T,
+			DelimiterPolicy::SpaceOnly => c == Token::SPACE,
+			DelimiterPolicy::Either => matches!(c, Token::T | Token::SPACE),
+		}
+	}
+}
 
 /// Parse a [proleptic-Gregorian date][proleptic-greg] consisting
 /// of a date, time, with no time-zone information
@@ -27,28 +65,139 @@ use chrono::NaiveDateTime;
 /// [proleptic-greg]: https://html.spec.whatwg.org/multipage/common-microsyntaxes.html#proleptic-gregorian-date
 /// [whatwg-html-local-datetime]: https://html.spec.whatwg.org/multipage/common-microsyntaxes.html#local-dates-and-times
 /// [whatwg-html-parse]: https://html.spec.whatwg.org/multipage/common-microsyntaxes.html#parse-a-local-date-and-time-string
+#[inline]
 pub fn parse_local_datetime(s: &str) -> Option<NaiveDateTime> {
-	let mut position = 0usize;
-	let date = parse_date_component(s, &mut position)?;
+	parse_local_datetime_with(s, DelimiterPolicy::Either)
+}
 
-	let last_char = s.chars().nth(position);
-	if position > s.len() || !matches!(last_char, Some(Token::T) | Some(Token::SPACE)) {
-		return None;
-	} else {
-		position += 1;
-	}
+/// Parse a string like [`parse_local_datetime`], after stripping leading and trailing ASCII whitespace
+#[inline]
+pub fn parse_local_datetime_trimmed(s: &str) -> Option<NaiveDateTime> {
+	parse_local_datetime(trim_ascii_whitespace(s))
+}
 
-	let time = parse_time_component(s, &mut position)?;
-	if position < s.len() {
+/// Parse a local datetime string, restricting which delimiters are
+/// accepted between the date and the time
+///
+/// This behaves the same as [`parse_local_datetime`], except that the
+/// delimiter must be accepted by the given [`DelimiterPolicy`].
+///
+/// # Examples
+/// ```
+/// use whatwg_datetime::{parse_local_datetime_with, DelimiterPolicy};
+///
+/// assert!(parse_local_datetime_with("2011-11-18 14:54", DelimiterPolicy::SpaceOnly).is_some());
+/// assert!(parse_local_datetime_with("2011-11-18T14:54", DelimiterPolicy::SpaceOnly).is_none());
+/// ```
+pub fn parse_local_datetime_with(s: &str, policy: DelimiterPolicy) -> Option<NaiveDateTime> {
+	parse_local_datetime_parts_with(s, policy)
+		.map(|(date, time)| NaiveDateTime::new(date, time))
+}
+
+/// Parse a local datetime string, returning the date and the time separately
+///
+/// This behaves the same as [`parse_local_datetime`], and is useful when
+/// the date and the time are stored separately.
+///
+/// # Examples
+/// ```
+/// use chrono::{NaiveDate, NaiveTime};
+/// use whatwg_datetime::parse_local_datetime_parts;
+///
+/// assert_eq!(
+///     parse_local_datetime_parts("2011-11-18T14:54"),
+///     Some((
+///         NaiveDate::from_ymd_opt(2011, 11, 18).unwrap(),
+///         NaiveTime::from_hms_opt(14, 54, 0).unwrap(),
+///     ))
+/// );
+/// ```
+#[inline]
+pub fn parse_local_datetime_parts(s: &str) -> Option<(NaiveDate, NaiveTime)> {
+	parse_local_datetime_parts_with(s, DelimiterPolicy::Either)
+}
+
+fn parse_local_datetime_parts_with(
+	s: &str,
+	policy: DelimiterPolicy,
+) -> Option<(NaiveDate, NaiveTime)> {
+	let mut p = Parser::new(s);
+	let date = p.date()?;
+	p.delimiter(policy)?;
+	let time = p.time()?;
+	p.ensure_end()?;
+
+	Some((date, time))
+}
+
+/// Parse a [valid normalized local date and time string][whatwg-html-normalized]
+///
+/// This behaves the same as [`parse_local_datetime`], except that the
+/// date and time must be separated by a `T`, and the time must be in its
+/// shortest form (see [`is_normalized_time_string`]).
+///
+/// # Examples
+/// ```
+/// use whatwg_datetime::parse_normalized_local_datetime;
+///
+/// assert!(parse_normalized_local_datetime("2011-11-18T14:54").is_some());
+/// assert!(parse_normalized_local_datetime("2011-11-18T14:54:00").is_none());
+/// assert!(parse_normalized_local_datetime("2011-11-18 14:54").is_none());
+/// ```
+///
+/// [whatwg-html-normalized]: https://html.spec.whatwg.org/multipage/common-microsyntaxes.html#valid-normalised-local-date-and-time-string
+pub fn parse_normalized_local_datetime(s: &str) -> Option<NaiveDateTime> {
+	let datetime = parse_local_datetime_with(s, DelimiterPolicy::TOnly)?;
+
+	// The date never contains a `T`, so the time is everything after it
+	let (_, time) = s.split_once(Token::T)?;
+	if !is_normalized_time_string(time) {
 		return None;
 	}
 
+	Some(datetime)
+}
+
+/// Parse a date string and a time string separately, and combine them
+/// into a local datetime
+///
+/// This is useful when a date and a time are given as separate fields,
+/// such as from an `<input type="date">` and an `<input type="time">`.
+/// Each string is parsed with [`parse_date`] and [`parse_time`],
+/// respectively, and this returns [`None`] if either of them fails.
+///
+/// # Examples
+/// ```
+/// use chrono::{NaiveDate, NaiveDateTime, NaiveTime};
+/// use whatwg_datetime::combine_date_time;
+///
+/// assert_eq!(
+///     combine_date_time("2011-11-18", "14:54:39"),
+///     Some(NaiveDateTime::new(
+///         NaiveDate::from_ymd_opt(2011, 11, 18).unwrap(),
+///         NaiveTime::from_hms_opt(14, 54, 39).unwrap(),
+///     ))
+/// );
+/// assert_eq!(combine_date_time("2011-11-18", "25:00"), None);
+/// ```
+pub fn combine_date_time(date_str: &str, time_str: &str) -> Option<NaiveDateTime> {
+	let date = parse_date(date_str)?;
+	let time = parse_time(time_str)?;
+
 	Some(NaiveDateTime::new(date, time))
 }
 
 #[cfg(test)]
 mod tests {
-	use super::parse_local_datetime;
+	#[rustfmt::skip]
+	use super::{
+		combine_date_time,
+		parse_local_datetime,
+		parse_local_datetime_parts,
+		parse_local_datetime_with,
+		parse_normalized_local_datetime,
+		DelimiterPolicy,
+	};
 	use chrono::{NaiveDate, NaiveDateTime, NaiveTime};
 
 	#[test]
@@ -131,4 +280,122 @@ mod tests {
 	pub fn test_parse_local_datetime_fails_invalid_time() {
 		assert_eq!(parse_local_datetime("2011-11-18T14/54/39"), None);
 	}
+
+	#[test]
+	fn test_parse_local_datetime_with_t_only() {
+		let parsed = parse_local_datetime_with("2011-11-18T14:54", DelimiterPolicy::TOnly);
+		assert_eq!(parsed, parse_local_datetime("2011-11-18T14:54"));
+		assert!(parsed.is_some());
+	}
+
+	#[test]
+	fn test_parse_local_datetime_with_t_only_fails_space() {
+		let parsed = parse_local_datetime_with("2011-11-18 14:54", DelimiterPolicy::TOnly);
+		assert_eq!(parsed, None);
+	}
+
+	#[test]
+	fn test_parse_local_datetime_with_space_only() {
+		let parsed =
+			parse_local_datetime_with("2011-11-18 14:54", DelimiterPolicy::SpaceOnly);
+		assert_eq!(parsed, parse_local_datetime("2011-11-18 14:54"));
+		assert!(parsed.is_some());
+	}
+
+	#[test]
+	fn test_parse_local_datetime_with_space_only_fails_t() {
+		let parsed =
+			parse_local_datetime_with("2011-11-18T14:54", DelimiterPolicy::SpaceOnly);
+		assert_eq!(parsed, None);
+	}
+
+	#[test]
+	fn test_parse_normalized_local_datetime() {
+		assert_eq!(
+			parse_normalized_local_datetime("2011-11-18T14:54"),
+			parse_local_datetime("2011-11-18T14:54")
+		);
+		assert_eq!(
+			parse_normalized_local_datetime("2011-11-18T14:54:39.9"),
+			parse_local_datetime("2011-11-18T14:54:39.9")
+		);
+	}
+
+	#[test]
+	fn test_parse_normalized_local_datetime_fails_zero_seconds() {
+		assert!(parse_local_datetime("2011-11-18T14:54:00").is_some());
+		assert_eq!(parse_normalized_local_datetime("2011-11-18T14:54:00"), None);
+	}
+
+	#[test]
+	fn test_parse_normalized_local_datetime_fails_trailing_zeros() {
+		assert_eq!(
+			parse_normalized_local_datetime("2011-11-18T14:54:39.900"),
+			None
+		);
+	}
+
+	#[test]
+	fn test_parse_normalized_local_datetime_fails_space_delimiter() {
+		assert_eq!(parse_normalized_local_datetime("2011-11-18 14:54"), None);
+	}
+
+	#[test]
+	fn test_combine_date_time() {
+		assert_eq!(
+			combine_date_time("2011-11-18", "14:54"),
+			Some(NaiveDateTime::new(
+				NaiveDate::from_ymd_opt(2011, 11, 18).unwrap(),
+				NaiveTime::from_hms_opt(14, 54, 0).unwrap(),
+			))
+		);
+		assert_eq!(
+			combine_date_time("2012-02-29", "00:00:00.5"),
+			Some(NaiveDateTime::new(
+				NaiveDate::from_ymd_opt(2012, 2, 29).unwrap(),
+				NaiveTime::from_hms_milli_opt(0, 0, 0, 500).unwrap(),
+			))
+		);
+	}
+
+	#[test]
+	fn test_combine_date_time_fails_time() {
+		assert_eq!(combine_date_time("2011-11-18", "14:60"), None);
+		assert_eq!(combine_date_time("2011-11-18", ""), None);
+	}
+
+	#[test]
+	fn test_combine_date_time_fails_date() {
+		assert_eq!(combine_date_time("2011-11-31", "14:54"), None);
+		assert_eq!(combine_date_time("2011-11-18T14:54", "14:54"), None);
+	}
+
+	#[test]
+	fn test_parse_local_datetime_parts_delimited_t() {
+		assert_eq!(
+			parse_local_datetime_parts("2011-11-18T14:54:39.929"),
+			Some((
+				NaiveDate::from_ymd_opt(2011, 11, 18).unwrap(),
+				NaiveTime::from_hms_milli_opt(14, 54, 39, 929).unwrap(),
+			))
+		);
+	}
+
+	#[test]
+	fn test_parse_local_datetime_parts_delimited_space() {
+		assert_eq!(
+			parse_local_datetime_parts("2011-11-18 14:54"),
+			Some((
+				NaiveDate::from_ymd_opt(2011, 11, 18).unwrap(),
+				NaiveTime::from_hms_opt(14, 54, 0).unwrap(),
+			))
+		);
+	}
+
+	#[test]
+	fn test_parse_local_datetime_parts_fails() {
+		assert_eq!(parse_local_datetime_parts("2011-11-18"), None);
+		assert_eq!(parse_local_datetime_parts("2011-11-18T14:54Z"), None);
+		assert_eq!(parse_local_datetime_parts("2011-11-18_14:54"), None);
+	}
 }
@@ -1,22 +1,35 @@
+mod any_datetime;
+mod canonical;
 mod date;
+mod duration;
+mod error;
 mod global_datetime;
 mod local_datetime;
 mod month;
+mod number;
+mod parser;
 mod time;
 mod timezone_offset;
-pub(crate) mod tokens;
+/// Delimiter constants shared by the datetime parsers
+pub mod tokens;
 mod week;
 mod yearless_date;
 
+pub use self::any_datetime::*;
+pub use self::canonical::*;
 pub use self::date::*;
+pub use self::duration::*;
+pub use self::error::*;
 pub use self::global_datetime::*;
 pub use self::local_datetime::*;
 pub use self::month::*;
+pub use self::number::*;
+pub use self::parser::*;
 pub use self::time::*;
 pub use self::timezone_offset::*;
 pub use self::week::*;
 pub use self::yearless_date::*;
-use crate::utils::collect_ascii_digits;
+use crate::utils::collect_integer;
 use crate::utils::is_valid_month;
 use crate::utils::max_days_in_month_year;
 
@@ -33,13 +46,105 @@ where
 	Some(parsed)
 }
 
-pub(crate) fn collect_day_and_validate(s: &str, position: &mut usize, month: u32) -> Option<u32> {
-	let parsed_day = collect_ascii_digits(s, position);
-	if parsed_day.len() != 2 {
-		return None;
+/// Parse a string with a low-level component parsing function, requiring
+/// that the entire string is consumed
+///
+/// This behaves like the top-level parsing functions (e.g [`parse_month`]),
+/// except that on failure, it returns a [`DatetimeParseError`] which
+/// preserves the position where parsing stopped.
+///
+/// # Examples
+/// ```
+/// use whatwg_datetime::{parse_exact_tracked, parse_month_component, DatetimeParseError, YearMonth};
+///
+/// assert_eq!(
+///     parse_exact_tracked("2011-11", parse_month_component).ok(),
+///     YearMonth::new_opt(2011, 11)
+/// );
+/// assert_eq!(
+///     parse_exact_tracked("2011-11garbage", parse_month_component),
+///     Err(DatetimeParseError::TrailingGarbage { position: 7 })
+/// );
+/// ```
+pub fn parse_exact_tracked<T, F>(s: &str, parse_fn: F) -> Result<T, DatetimeParseError>
+where
+	F: FnOnce(&str, &mut usize) -> Option<T>,
+{
+	let mut position = 0usize;
+	let parsed = match parse_fn(s, &mut position) {
+		Some(parsed) => parsed,
+		None => return Err(DatetimeParseError::Invalid { position }),
+	};
+
+	if position < s.len() {
+		return Err(DatetimeParseError::TrailingGarbage { position });
+	}
+
+	Ok(parsed)
+}
+
+/// Combine two low-level component parsing functions into one, which runs
+/// them in sequence and returns both of their results
+///
+/// The position is threaded from the first parser into the second, and
+/// parsing stops at the first parser which returns [`None`]. Use a closure
+/// as one of the parsers to consume a delimiter between two components.
+///
+/// # Examples
+/// ```
+/// use chrono::{NaiveDate, NaiveTime};
+/// use whatwg_datetime::{parse_date_component, parse_exact_tracked, parse_time_component, seq3};
+///
+/// let delimiter = |s: &str, position: &mut usize| {
+///     s[*position..].starts_with('T').then(|| *position += 1)
+/// };
+/// let parse_fn = seq3(parse_date_component, delimiter, parse_time_component);
+///
+/// assert_eq!(
+///     parse_exact_tracked("2011-11-18T14:54", parse_fn).ok(),
+///     Some((
+///         NaiveDate::from_ymd_opt(2011, 11, 18).unwrap(),
+///         (),
+///         NaiveTime::from_hms_opt(14, 54, 0).unwrap(),
+///     ))
+/// );
+/// ```
+pub fn seq2<A, B, PA, PB>(pa: PA, pb: PB) -> impl Fn(&str, &mut usize) -> Option<(A, B)>
+where
+	PA: Fn(&str, &mut usize) -> Option<A>,
+	PB: Fn(&str, &mut usize) -> Option<B>,
+{
+	move |s, position| {
+		let a = pa(s, position)?;
+		let b = pb(s, position)?;
+		Some((a, b))
 	}
+}
+
+/// Combine three low-level component parsing functions into one, which runs
+/// them in sequence and returns each of their results
+///
+/// See [`seq2`] for more details.
+pub fn seq3<A, B, C, PA, PB, PC>(
+	pa: PA,
+	pb: PB,
+	pc: PC,
+) -> impl Fn(&str, &mut usize) -> Option<(A, B, C)>
+where
+	PA: Fn(&str, &mut usize) -> Option<A>,
+	PB: Fn(&str, &mut usize) -> Option<B>,
+	PC: Fn(&str, &mut usize) -> Option<C>,
+{
+	move |s, position| {
+		let a = pa(s, position)?;
+		let b = pb(s, position)?;
+		let c = pc(s, position)?;
+		Some((a, b, c))
+	}
+}
 
-	let day = parsed_day.parse::<u32>().ok()?;
+pub(crate) fn collect_day_and_validate(s: &str, position: &mut usize, month: u32) -> Option<u32> {
+	let (day, _) = collect_integer(s, position, 2, 2, true)?;
 	let max_days = max_days_in_month_year(month, 4).unwrap();
 	if !(1..=max_days).contains(&day) {
 		return None;
@@ -49,15 +154,172 @@ pub(crate) fn collect_day_and_validate(s: &str, position: &mut usize, month: u32
 }
 
 pub(crate) fn collect_month_and_validate(s: &str, position: &mut usize) -> Option<u32> {
-	let parsed_month = collect_ascii_digits(s, position);
-	if parsed_month.len() != 2 {
-		return None;
-	}
-
-	let month = parsed_month.parse::<u32>().ok()?;
+	let (month, _) = collect_integer(s, position, 2, 2, true)?;
 	if !is_valid_month(&month) {
 		return None;
 	}
 
 	Some(month)
 }
+
+#[cfg(test)]
+mod tests {
+	use super::{parse_exact_tracked, parse_format, seq2, seq3, DatetimeParseError};
+	use crate::tokens::Token;
+	use crate::utils::ascii_char_at;
+	use crate::{
+		parse_date, parse_date_component, parse_date_trimmed, parse_global_datetime,
+		parse_global_datetime_trimmed, parse_local_datetime, parse_local_datetime_trimmed,
+		parse_month, parse_month_component, parse_month_trimmed, parse_time,
+		parse_time_component, parse_time_trimmed, parse_timezone_offset,
+		parse_timezone_offset_component, parse_timezone_offset_trimmed, parse_week,
+		parse_week_trimmed, parse_yearless_date, parse_yearless_date_trimmed,
+		TimeZoneOffset, YearMonth,
+	};
+	use chrono::{NaiveDate, NaiveTime};
+	use core::fmt::Debug;
+	use whatwg_infra::trim_ascii_whitespace;
+
+	fn parse_t(s: &str, position: &mut usize) -> Option<()> {
+		if ascii_char_at(s, *position) != Some(Token::T) {
+			return None;
+		}
+
+		*position += 1;
+		Some(())
+	}
+
+	#[test]
+	fn test_seq2() {
+		let parse_fn = seq2(parse_time_component, parse_timezone_offset_component);
+		assert_eq!(
+			parse_format("14:54-07:00", &parse_fn),
+			Some((
+				NaiveTime::from_hms_opt(14, 54, 0).unwrap(),
+				TimeZoneOffset::new(-7, 0)
+			))
+		);
+	}
+
+	#[test]
+	fn test_seq2_threads_position() {
+		let parse_fn = seq2(parse_date_component, parse_t);
+		let mut position = 0usize;
+		assert_eq!(
+			parse_fn("2011-11-18T14:54", &mut position),
+			Some((NaiveDate::from_ymd_opt(2011, 11, 18).unwrap(), ()))
+		);
+		assert_eq!(position, 11);
+	}
+
+	#[test]
+	fn test_seq2_fails_short_circuits() {
+		let parse_fn = seq2(parse_date_component, parse_t);
+		let mut position = 0usize;
+		assert_eq!(parse_fn("2011-13-18T14:54", &mut position), None);
+		assert_eq!(position, 7);
+
+		let mut position = 0usize;
+		assert_eq!(parse_fn("2011-11-18 14:54", &mut position), None);
+		assert_eq!(position, 10);
+	}
+
+	#[test]
+	fn test_seq3_local_datetime() {
+		let parse_fn = seq3(parse_date_component, parse_t, parse_time_component);
+		assert_eq!(
+			parse_format("2011-11-18T14:54:39", &parse_fn),
+			Some((
+				NaiveDate::from_ymd_opt(2011, 11, 18).unwrap(),
+				(),
+				NaiveTime::from_hms_opt(14, 54, 39).unwrap()
+			))
+		);
+		assert_eq!(parse_format("2011-11-18T14:54:39Z", &parse_fn), None);
+	}
+
+	#[test]
+	fn test_parse_exact_tracked() {
+		assert_eq!(
+			parse_exact_tracked("2011-11", parse_month_component),
+			Ok(YearMonth::new(2011, 11))
+		);
+	}
+
+	#[test]
+	fn test_parse_exact_tracked_fails_trailing_garbage() {
+		let s = "2011-11garbage";
+		let parsed = parse_exact_tracked(s, parse_month_component);
+
+		assert_eq!(
+			parsed,
+			Err(DatetimeParseError::TrailingGarbage { position: 7 })
+		);
+		assert_eq!(s[7..].chars().next(), Some('g'));
+	}
+
+	#[test]
+	fn test_parse_exact_tracked_fails_invalid() {
+		assert_eq!(
+			parse_exact_tracked("2011-13-01", parse_date_component),
+			Err(DatetimeParseError::Invalid { position: 7 })
+		);
+	}
+
+	// A string with surrounding whitespace, and a check for it against a `*_trimmed` parser
+	type TrimmedCase = (&'static str, fn(&str));
+
+	// Checks that a `*_trimmed` parser accepts a string with surrounding whitespace,
+	// which its strict parser rejects
+	fn check_trimmed<T: PartialEq + Debug>(
+		s: &str,
+		trimmed: fn(&str) -> Option<T>,
+		strict: fn(&str) -> Option<T>,
+	) {
+		assert!(trimmed(s).is_some(), "{s:?}");
+		assert_eq!(trimmed(s), strict(trim_ascii_whitespace(s)), "{s:?}");
+		assert_eq!(strict(s), None, "{s:?}");
+	}
+
+	#[test]
+	fn test_parse_trimmed() {
+		let cases: [TrimmedCase; 8] = [
+			("\t2011-11-18 ", |s| {
+				check_trimmed(s, parse_date_trimmed, parse_date)
+			}),
+			("  14:54:39  ", |s| {
+				check_trimmed(s, parse_time_trimmed, parse_time)
+			}),
+			("  2011-11  ", |s| {
+				check_trimmed(s, parse_month_trimmed, parse_month)
+			}),
+			(" 2011-W47 ", |s| {
+				check_trimmed(s, parse_week_trimmed, parse_week)
+			}),
+			(" 11-18\r\n", |s| {
+				check_trimmed(s, parse_yearless_date_trimmed, parse_yearless_date)
+			}),
+			(" 2011-11-18 14:54 ", |s| {
+				check_trimmed(s, parse_local_datetime_trimmed, parse_local_datetime)
+			}),
+			(" 2011-11-18T14:54Z\n", |s| {
+				check_trimmed(
+					s,
+					parse_global_datetime_trimmed,
+					parse_global_datetime,
+				)
+			}),
+			("  +05:30\t", |s| {
+				check_trimmed(
+					s,
+					parse_timezone_offset_trimmed,
+					parse_timezone_offset,
+				)
+			}),
+		];
+
+		for (s, check) in cases {
+			check(s);
+		}
+	}
+}
@@ -1,6 +1,10 @@
 use crate::tokens::Token;
-use crate::utils::{collect_ascii_digits, is_valid_month};
+use crate::utils::{
+	ascii_char_at, collect_ascii_digits, collect_ascii_digits_str, is_leap_year, is_valid_month,
+};
 use crate::{collect_month_and_validate, parse_format};
+use chrono::NaiveDate;
+use whatwg_infra::trim_ascii_whitespace;
 
 /// A [proleptic-Gregorian date][proleptic-greg] consisting of a year and a month,
 /// with no time-zone or date information.
@@ -13,7 +17,7 @@ use crate::{collect_month_and_validate, parse_format};
 /// ```
 ///
 /// [proleptic-greg]: https://html.spec.whatwg.org/multipage/common-microsyntaxes.html#proleptic-gregorian-date
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
 pub struct YearMonth {
 	pub(crate) year: i32,
 	pub(crate) month: u32,
@@ -77,6 +81,170 @@ impl YearMonth {
 	pub const fn month(&self) -> u32 {
 		self.month
 	}
+
+	/// The quarter of the year which the month is in, from 1 to 4, inclusive.
+	///
+	/// # Examples
+	/// ```
+	/// use whatwg_datetime::YearMonth;
+	///
+	/// assert_eq!(YearMonth::new_opt(2011, 3).unwrap().quarter(), 1);
+	/// assert_eq!(YearMonth::new_opt(2011, 4).unwrap().quarter(), 2);
+	/// assert_eq!(YearMonth::new_opt(2011, 11).unwrap().quarter(), 4);
+	/// ```
+	#[inline]
+	pub const fn quarter(&self) -> u32 {
+		(self.month - 1) / 3 + 1
+	}
+
+	/// The half of the year which the month is in, either 1 or 2.
+	///
+	/// # Examples
+	/// ```
+	/// use whatwg_datetime::YearMonth;
+	///
+	/// assert_eq!(YearMonth::new_opt(2011, 6).unwrap().half(), 1);
+	/// assert_eq!(YearMonth::new_opt(2011, 7).unwrap().half(), 2);
+	/// ```
+	#[inline]
+	pub const fn half(&self) -> u32 {
+		(self.month - 1) / 6 + 1
+	}
+
+	/// The number of days in the month, taking leap years into account.
+	///
+	/// # Examples
+	/// ```
+	/// use whatwg_datetime::YearMonth;
+	///
+	/// assert_eq!(YearMonth::new_opt(2011, 11).unwrap().days_in_month(), 30);
+	/// assert_eq!(YearMonth::new_opt(2020, 2).unwrap().days_in_month(), 29);
+	/// assert_eq!(YearMonth::new_opt(2021, 2).unwrap().days_in_month(), 28);
+	/// ```
+	pub const fn days_in_month(&self) -> u32 {
+		match self.month {
+			4 | 6 | 9 | 11 => 30,
+			2 if is_leap_year(self.year) => 29,
+			2 => 28,
+			_ => 31,
+		}
+	}
+
+	/// An iterator over each date in the month, from the first day
+	/// through the last day of the month.
+	///
+	/// # Examples
+	/// ```
+	/// use chrono::NaiveDate;
+	/// use whatwg_datetime::YearMonth;
+	///
+	/// let year_month = YearMonth::new_opt(2011, 11).unwrap();
+	/// let mut days = year_month.days();
+	///
+	/// assert_eq!(days.next(), NaiveDate::from_ymd_opt(2011, 11, 1));
+	/// assert_eq!(days.last(), NaiveDate::from_ymd_opt(2011, 11, 30));
+	/// ```
+	pub fn days(&self) -> impl Iterator<Item = NaiveDate> {
+		let (year, month) = (self.year, self.month);
+		(1..=self.days_in_month())
+			.filter_map(move |day| NaiveDate::from_ymd_opt(year, month, day))
+	}
+
+	/// The next month, rolling over from December to January of the next year.
+	///
+	/// This returns [`None`] if the year would overflow.
+	///
+	/// # Examples
+	/// ```
+	/// use whatwg_datetime::YearMonth;
+	///
+	/// let month = YearMonth::new_opt(2011, 11).unwrap();
+	/// assert_eq!(month.succ(), YearMonth::new_opt(2011, 12));
+	///
+	/// let month = YearMonth::new_opt(2011, 12).unwrap();
+	/// assert_eq!(month.succ(), YearMonth::new_opt(2012, 1));
+	/// ```
+	pub fn succ(&self) -> Option<Self> {
+		if self.month < 12 {
+			Some(Self::new(self.year, self.month + 1))
+		} else {
+			Some(Self::new(self.year.checked_add(1)?, 1))
+		}
+	}
+
+	/// The previous month, rolling over from January to December of the previous year.
+	///
+	/// This returns [`None`] for January of the year 1, since the year
+	/// must be greater than 0.
+	///
+	/// # Examples
+	/// ```
+	/// use whatwg_datetime::YearMonth;
+	///
+	/// let month = YearMonth::new_opt(2011, 1).unwrap();
+	/// assert_eq!(month.pred(), YearMonth::new_opt(2010, 12));
+	///
+	/// let month = YearMonth::new_opt(1, 1).unwrap();
+	/// assert_eq!(month.pred(), None);
+	/// ```
+	pub fn pred(&self) -> Option<Self> {
+		if self.month > 1 {
+			Some(Self::new(self.year, self.month - 1))
+		} else if self.year > 1 {
+			Some(Self::new(self.year - 1, 12))
+		} else {
+			None
+		}
+	}
+
+	/// Restricts the month to be at least `min` and at most `max`, like the
+	/// [minimum][whatwg-html-min] and [maximum][whatwg-html-max] of an
+	/// `<input type=month>` element.
+	///
+	/// Either bound may be omitted. If `min` is after `max`, a month before
+	/// `min` is clamped up to `min`.
+	///
+	/// # Examples
+	/// ```
+	/// use whatwg_datetime::YearMonth;
+	///
+	/// let min = YearMonth::new_opt(2011, 1);
+	/// let max = YearMonth::new_opt(2011, 12);
+	///
+	/// let month = YearMonth::new_opt(2010, 6).unwrap();
+	/// assert_eq!(Some(month.clamp_to_range(min, max)), min);
+	///
+	/// let month = YearMonth::new_opt(2011, 11).unwrap();
+	/// assert_eq!(month.clamp_to_range(min, max), month);
+	/// ```
+	///
+	/// [whatwg-html-min]: https://html.spec.whatwg.org/multipage/input.html#concept-input-min
+	/// [whatwg-html-max]: https://html.spec.whatwg.org/multipage/input.html#concept-input-max
+	pub fn clamp_to_range(self, min: Option<YearMonth>, max: Option<YearMonth>) -> YearMonth {
+		match (min, max) {
+			(Some(min), _) if self < min => min,
+			(_, Some(max)) if self > max => max,
+			_ => self,
+		}
+	}
+
+	/// Whether the month is at least `min` and at most `max`, where either
+	/// bound may be omitted.
+	///
+	/// # Examples
+	/// ```
+	/// use whatwg_datetime::YearMonth;
+	///
+	/// let min = YearMonth::new_opt(2011, 1);
+	/// let max = YearMonth::new_opt(2011, 12);
+	///
+	/// assert!(YearMonth::new_opt(2011, 11).unwrap().is_in_range(min, max));
+	/// assert!(!YearMonth::new_opt(2012, 1).unwrap().is_in_range(min, max));
+	/// assert!(YearMonth::new_opt(2012, 1).unwrap().is_in_range(min, None));
+	/// ```
+	pub fn is_in_range(&self, min: Option<YearMonth>, max: Option<YearMonth>) -> bool {
+		min.iter().all(|min| self >= min) && max.iter().all(|max| self <= max)
+	}
 }
 
 /// Parse a [proleptic-Gregorian date][proleptic-greg] consisting of a year and a month,
@@ -100,6 +268,48 @@ pub fn parse_month(s: &str) -> Option<YearMonth> {
 	parse_format(s, parse_month_component)
 }
 
+/// Parse a string like [`parse_month`], after stripping leading and trailing ASCII whitespace
+#[inline]
+pub fn parse_month_trimmed(s: &str) -> Option<YearMonth> {
+	parse_month(trim_ascii_whitespace(s))
+}
+
+/// Split a month string into its year and month, without parsing them
+/// into integers
+///
+/// This checks that the string is a year of at least 4 digits which isn't
+/// zero, a hyphen, and a valid 2-digit month, returning the year and the
+/// month as slices of the input. This is useful for preserving the exact
+/// digits that were written, such as the width of the year. Unlike
+/// [`parse_month`], the year isn't checked to fit in an integer.
+///
+/// # Examples
+/// ```
+/// use whatwg_datetime::split_month_string;
+///
+/// assert_eq!(split_month_string("0099-03"), Some(("0099", "03")));
+/// assert_eq!(split_month_string("2011-13"), None);
+/// ```
+pub fn split_month_string(s: &str) -> Option<(&str, &str)> {
+	let mut position = 0usize;
+	let year = collect_ascii_digits_str(s, &mut position);
+	if year.len() < 4 || year.bytes().all(|b| b == b'0') {
+		return None;
+	}
+
+	if ascii_char_at(s, position) != Some(Token::HYPHEN) {
+		return None;
+	}
+	position += 1;
+
+	let month = collect_ascii_digits_str(s, &mut position);
+	if month.len() != 2 || !("01"..="12").contains(&month) || position < s.len() {
+		return None;
+	}
+
+	Some((year, month))
+}
+
 /// Low-level function for parsing an individual month component at a given position
 ///
 /// This follows the rules for [parsing a month component][whatwg-html-parse]
@@ -121,18 +331,38 @@ pub fn parse_month(s: &str) -> Option<YearMonth> {
 ///
 /// [whatwg-html-months]: https://html.spec.whatwg.org/multipage/common-microsyntaxes.html#months
 /// [whatwg-html-parse]: https://html.spec.whatwg.org/multipage/common-microsyntaxes.html#parse-a-month-component
+#[inline]
 pub fn parse_month_component(s: &str, position: &mut usize) -> Option<YearMonth> {
+	parse_month_component_impl(s, position, false, Token::HYPHEN)
+}
+
+// If `proleptic` is true, the year may also be zero, or negative with a leading `-`.
+// The `separator` is placed between the year and the month, which is a hyphen per spec.
+pub(crate) fn parse_month_component_impl(
+	s: &str,
+	position: &mut usize,
+	proleptic: bool,
+	separator: char,
+) -> Option<YearMonth> {
+	let is_negative = proleptic && ascii_char_at(s, *position) == Some(Token::HYPHEN);
+	if is_negative {
+		*position += 1;
+	}
+
 	let parsed_year = collect_ascii_digits(s, position);
 	if parsed_year.len() < 4 {
 		return None;
 	}
 
-	let year = parsed_year.parse::<i32>().ok()?;
-	if year == 0 {
+	let mut year = parsed_year.parse::<i32>().ok()?;
+	if year == 0 && !proleptic {
 		return None;
 	}
+	if is_negative {
+		year = -year;
+	}
 
-	if *position > s.len() || s.chars().nth(*position) != Some(Token::HYPHEN) {
+	if ascii_char_at(s, *position) != Some(separator) {
 		return None;
 	} else {
 		*position += 1;
@@ -144,7 +374,147 @@ pub fn parse_month_component(s: &str, position: &mut usize) -> Option<YearMonth>
 
 #[cfg(test)]
 mod tests {
-	use super::{parse_month, parse_month_component, YearMonth};
+	#[rustfmt::skip]
+	use super::{
+		parse_month,
+		parse_month_component,
+		split_month_string,
+		YearMonth,
+	};
+
+	#[test]
+	fn test_year_month_quarter() {
+		#[rustfmt::skip]
+		let cases = [
+			(1, 1), (3, 1),
+			(4, 2), (6, 2),
+			(7, 3), (9, 3),
+			(10, 4), (12, 4),
+		];
+
+		for (month, quarter) in cases {
+			assert_eq!(YearMonth::new(2011, month).quarter(), quarter);
+		}
+	}
+
+	#[test]
+	fn test_year_month_half() {
+		#[rustfmt::skip]
+		let cases = [
+			(1, 1), (6, 1),
+			(7, 2), (12, 2),
+		];
+
+		for (month, half) in cases {
+			assert_eq!(YearMonth::new(2011, month).half(), half);
+		}
+	}
+
+	#[test]
+	fn test_year_month_succ() {
+		assert_eq!(
+			YearMonth::new(2011, 11).succ(),
+			Some(YearMonth::new(2011, 12))
+		);
+		assert_eq!(
+			YearMonth::new(2011, 12).succ(),
+			Some(YearMonth::new(2012, 1))
+		);
+		assert_eq!(YearMonth::new(i32::MAX, 12).succ(), None);
+	}
+
+	#[test]
+	fn test_year_month_pred() {
+		assert_eq!(
+			YearMonth::new(2011, 12).pred(),
+			Some(YearMonth::new(2011, 11))
+		);
+		assert_eq!(
+			YearMonth::new(2011, 1).pred(),
+			Some(YearMonth::new(2010, 12))
+		);
+		assert_eq!(YearMonth::new(1, 2).pred(), Some(YearMonth::new(1, 1)));
+		assert_eq!(YearMonth::new(1, 1).pred(), None);
+	}
+
+	#[test]
+	fn test_year_month_succ_pred_round_trip() {
+		let month = YearMonth::new(2011, 12);
+		assert_eq!(month.succ().and_then(|m| m.pred()), Some(month));
+		assert_eq!(month.pred().and_then(|m| m.succ()), Some(month));
+	}
+
+	#[test]
+	fn test_year_month_ord() {
+		assert!(YearMonth::new(2011, 12) < YearMonth::new(2012, 1));
+		assert!(YearMonth::new(2011, 11) < YearMonth::new(2011, 12));
+		assert!(YearMonth::new(2012, 1) > YearMonth::new(2011, 12));
+	}
+
+	#[test]
+	fn test_year_month_clamp_to_range_below_min() {
+		let min = Some(YearMonth::new(2011, 1));
+		let max = Some(YearMonth::new(2011, 12));
+		assert_eq!(
+			YearMonth::new(2010, 12).clamp_to_range(min, max),
+			YearMonth::new(2011, 1)
+		);
+	}
+
+	#[test]
+	fn test_year_month_clamp_to_range_above_max() {
+		let min = Some(YearMonth::new(2011, 1));
+		let max = Some(YearMonth::new(2011, 12));
+		assert_eq!(
+			YearMonth::new(2012, 1).clamp_to_range(min, max),
+			YearMonth::new(2011, 12)
+		);
+	}
+
+	#[test]
+	fn test_year_month_clamp_to_range_within_range() {
+		let min = Some(YearMonth::new(2011, 1));
+		let max = Some(YearMonth::new(2011, 12));
+		assert_eq!(
+			YearMonth::new(2011, 6).clamp_to_range(min, max),
+			YearMonth::new(2011, 6)
+		);
+		assert_eq!(
+			YearMonth::new(2011, 6).clamp_to_range(None, None),
+			YearMonth::new(2011, 6)
+		);
+	}
+
+	#[test]
+	fn test_year_month_ord_clamp() {
+		let min = YearMonth::new(2011, 1);
+		let max = YearMonth::new(2011, 12);
+		assert_eq!(YearMonth::new(2010, 12).clamp(min, max), min);
+	}
+
+	#[test]
+	fn test_year_month_is_in_range() {
+		let min = Some(YearMonth::new(2011, 1));
+		let max = Some(YearMonth::new(2011, 12));
+		assert!(YearMonth::new(2011, 1).is_in_range(min, max));
+		assert!(YearMonth::new(2011, 12).is_in_range(min, max));
+		assert!(!YearMonth::new(2010, 12).is_in_range(min, max));
+		assert!(!YearMonth::new(2012, 1).is_in_range(min, max));
+		assert!(YearMonth::new(1, 1).is_in_range(None, max));
+		assert!(YearMonth::new(9999, 12).is_in_range(min, None));
+	}
+
+	#[test]
+	fn test_year_month_days_leap_year() {
+		let year_month = YearMonth::new(2020, 2);
+		assert_eq!(year_month.days().count(), 29);
+	}
+
+	#[test]
+	fn test_year_month_days_not_leap_year() {
+		let year_month = YearMonth::new(2021, 2);
+		assert_eq!(year_month.days().count(), 28);
+	}
 
 	#[test]
 	fn test_parse_month_string() {
@@ -211,4 +581,59 @@ mod tests {
 
 		assert_eq!(parsed, None);
 	}
+
+	#[test]
+	fn test_parse_month_component_fails_multibyte_separator() {
+		let mut position = 0usize;
+		let parsed = parse_month_component("2004\u{2010}12", &mut position);
+
+		assert_eq!(parsed, None);
+	}
+
+	#[test]
+	fn test_parse_month_component_multibyte_prefix() {
+		let mut position = 2usize;
+		let parsed = parse_month_component("\u{E9}2011-11", &mut position);
+
+		assert_eq!(parsed, Some(YearMonth::new(2011, 11)));
+		assert_eq!(position, 9);
+	}
+
+	#[test]
+	fn test_parse_month_component_fails_not_char_boundary() {
+		let mut position = 1usize;
+		let parsed = parse_month_component("\u{E9}2011-11", &mut position);
+
+		assert_eq!(parsed, None);
+	}
+
+	#[test]
+	fn test_parse_month_fails_multibyte_trailing_content() {
+		assert_eq!(parse_month("2004-12\u{1F4C5}"), None);
+	}
+
+	#[test]
+	fn test_split_month_string() {
+		assert_eq!(split_month_string("12011-03"), Some(("12011", "03")));
+		assert_eq!(split_month_string("2011-11"), Some(("2011", "11")));
+		assert_eq!(split_month_string("0001-01"), Some(("0001", "01")));
+		assert_eq!(
+			split_month_string("99999999999-12"),
+			Some(("99999999999", "12"))
+		);
+	}
+
+	#[test]
+	fn test_split_month_string_fails() {
+		assert_eq!(split_month_string(""), None);
+		assert_eq!(split_month_string("0000-01"), None);
+		assert_eq!(split_month_string("201-01"), None);
+		assert_eq!(split_month_string("2011/01"), None);
+		assert_eq!(split_month_string("2011-00"), None);
+		assert_eq!(split_month_string("2011-13"), None);
+		assert_eq!(split_month_string("2011-1"), None);
+		assert_eq!(split_month_string("2011-011"), None);
+		assert_eq!(split_month_string("2011-01-18"), None);
+		assert_eq!(split_month_string("-2011-01"), None);
+	}
 }
@@ -0,0 +1,264 @@
+use crate::tokens::Token;
+use crate::utils::{ascii_char_at, collect_ascii_digits_str};
+
+/// Parse a [valid integer][whatwg-html-valid], which is one or more ASCII
+/// digits, optionally prefixed with a U+002D HYPHEN-MINUS (`-`)
+///
+/// Leading zeros are allowed, and `-0` is parsed as `0`. A leading `+`,
+/// surrounding whitespace, and trailing characters are rejected, as are
+/// values which don't fit in an `i64`.
+///
+/// # Examples
+/// ```
+/// use whatwg_datetime::parse_integer;
+///
+/// assert_eq!(parse_integer("-42"), Some(-42));
+/// assert_eq!(parse_integer("007"), Some(7));
+/// assert_eq!(parse_integer("+42"), None);
+/// assert_eq!(parse_integer("42px"), None);
+/// ```
+///
+/// [whatwg-html-valid]: https://html.spec.whatwg.org/multipage/common-microsyntaxes.html#valid-integer
+pub fn parse_integer(s: &str) -> Option<i64> {
+	let mut position = 0usize;
+	if ascii_char_at(s, position) == Some(Token::MINUS) {
+		position += 1;
+	}
+
+	let digits = collect_ascii_digits_str(s, &mut position);
+	if digits.is_empty() || position < s.len() {
+		return None;
+	}
+
+	s.parse::<i64>().ok()
+}
+
+/// Parse a [valid non-negative integer][whatwg-html-valid], which is one
+/// or more ASCII digits
+///
+/// Leading zeros are allowed. Any sign, including `-0`, is rejected, as are
+/// values which don't fit in a `u64`.
+///
+/// # Examples
+/// ```
+/// use whatwg_datetime::parse_non_negative_integer;
+///
+/// assert_eq!(parse_non_negative_integer("42"), Some(42));
+/// assert_eq!(parse_non_negative_integer("007"), Some(7));
+/// assert_eq!(parse_non_negative_integer("-0"), None);
+/// ```
+///
+/// [whatwg-html-valid]: https://html.spec.whatwg.org/multipage/common-microsyntaxes.html#valid-non-negative-integer
+pub fn parse_non_negative_integer(s: &str) -> Option<u64> {
+	let mut position = 0usize;
+	let digits = collect_ascii_digits_str(s, &mut position);
+	if digits.is_empty() || position < s.len() {
+		return None;
+	}
+
+	digits.parse::<u64>().ok()
+}
+
+/// Parse a [valid floating-point number][whatwg-html-valid], as used by
+/// `<input type=number>`
+///
+/// This consists of an optional U+002D HYPHEN-MINUS (`-`), an integer part,
+/// an optional fraction, and an optional exponent. Forms which
+/// [`f64::from_str`](core::str::FromStr) accepts but aren't valid per the
+/// WHATWG HTML Standard are rejected, such as a leading `+`, `5.`,
+/// `Infinity`, `NaN`, and surrounding whitespace.
+///
+/// Following the [rules for parsing floating-point number values][whatwg-html-parse],
+/// a value which rounds to infinity is rejected, and `-0` is parsed as `0`.
+///
+/// # Examples
+/// ```
+/// use whatwg_datetime::parse_floating_point;
+///
+/// assert_eq!(parse_floating_point("1.5e3"), Some(1500.0));
+/// assert_eq!(parse_floating_point("-.5"), Some(-0.5));
+/// assert_eq!(parse_floating_point("+1.5"), None);
+/// assert_eq!(parse_floating_point("Infinity"), None);
+/// ```
+///
+/// [whatwg-html-valid]: https://html.spec.whatwg.org/multipage/common-microsyntaxes.html#valid-floating-point-number
+/// [whatwg-html-parse]: https://html.spec.whatwg.org/multipage/common-microsyntaxes.html#rules-for-parsing-floating-point-number-values
+pub fn parse_floating_point(s: &str) -> Option<f64> {
+	let mut position = 0usize;
+	if ascii_char_at(s, position) == Some(Token::MINUS) {
+		position += 1;
+	}
+
+	// The integer part may only be omitted if there is a fraction
+	let integer = collect_ascii_digits_str(s, &mut position);
+	if ascii_char_at(s, position) == Some(Token::DOT) {
+		position += 1;
+		if collect_ascii_digits_str(s, &mut position).is_empty() {
+			return None;
+		}
+	} else if integer.is_empty() {
+		return None;
+	}
+
+	if matches!(ascii_char_at(s, position), Some('e' | 'E')) {
+		position += 1;
+		if matches!(ascii_char_at(s, position), Some(Token::MINUS | Token::PLUS)) {
+			position += 1;
+		}
+
+		if collect_ascii_digits_str(s, &mut position).is_empty() {
+			return None;
+		}
+	}
+
+	if position < s.len() {
+		return None;
+	}
+
+	// The string is now known to be in a form that `f64::from_str` parses
+	// with the same meaning, and rounds to the nearest value
+	let value = s.parse::<f64>().ok()?;
+	if value.is_infinite() {
+		return None;
+	}
+
+	if value == 0.0 {
+		Some(0.0)
+	} else {
+		Some(value)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::{parse_floating_point, parse_integer, parse_non_negative_integer};
+
+	#[test]
+	fn test_parse_integer() {
+		assert_eq!(parse_integer("0"), Some(0));
+		assert_eq!(parse_integer("42"), Some(42));
+		assert_eq!(parse_integer("-42"), Some(-42));
+		assert_eq!(parse_integer("-0"), Some(0));
+		assert_eq!(parse_integer("007"), Some(7));
+		assert_eq!(parse_integer("-007"), Some(-7));
+		assert_eq!(parse_integer("-9223372036854775808"), Some(i64::MIN));
+	}
+
+	#[test]
+	fn test_parse_integer_fails() {
+		for s in [
+			"",
+			"-",
+			"+1",
+			" 1",
+			"1 ",
+			"1.0",
+			"1e3",
+			"--1",
+			"0x10",
+			"1\u{FF11}",
+		] {
+			assert_eq!(parse_integer(s), None, "{:?}", s);
+		}
+	}
+
+	#[test]
+	fn test_parse_integer_fails_overflow() {
+		assert_eq!(parse_integer("9223372036854775808"), None);
+	}
+
+	#[test]
+	fn test_parse_non_negative_integer() {
+		assert_eq!(parse_non_negative_integer("0"), Some(0));
+		assert_eq!(parse_non_negative_integer("42"), Some(42));
+		assert_eq!(parse_non_negative_integer("007"), Some(7));
+		assert_eq!(
+			parse_non_negative_integer("18446744073709551615"),
+			Some(u64::MAX)
+		);
+	}
+
+	#[test]
+	fn test_parse_non_negative_integer_fails() {
+		for s in [
+			"",
+			"-0",
+			"-1",
+			"+1",
+			" 1",
+			"1 ",
+			"1.0",
+			"18446744073709551616",
+		] {
+			assert_eq!(parse_non_negative_integer(s), None, "{:?}", s);
+		}
+	}
+
+	#[test]
+	fn test_parse_floating_point() {
+		#[rustfmt::skip]
+		let cases = [
+			("0", 0.0),
+			("42", 42.0),
+			("-42", -42.0),
+			("1.5", 1.5),
+			(".5", 0.5),
+			("-.5", -0.5),
+			("1.5e3", 1500.0),
+			("1.5E3", 1500.0),
+			("1e+3", 1000.0),
+			("15e-1", 1.5),
+			("007", 7.0),
+		];
+
+		for (s, expected) in cases {
+			assert_eq!(parse_floating_point(s), Some(expected), "{:?}", s);
+		}
+	}
+
+	#[test]
+	fn test_parse_floating_point_negative_zero() {
+		let value = parse_floating_point("-0").unwrap();
+		assert_eq!(value, 0.0);
+		assert!(value.is_sign_positive());
+		assert!(parse_floating_point("-0.0e5").unwrap().is_sign_positive());
+	}
+
+	#[test]
+	fn test_parse_floating_point_fails_from_str_only_forms() {
+		for s in [
+			"+1",
+			"Infinity",
+			"-Infinity",
+			"inf",
+			"NaN",
+			"0x10",
+			"5.",
+			"-",
+			".",
+			"1e",
+			"1e+",
+			"e5",
+			" 1",
+			"1 ",
+			"",
+		] {
+			assert_eq!(parse_floating_point(s), None, "{:?}", s);
+		}
+	}
+
+	#[test]
+	fn test_parse_floating_point_fails_multiple_parts() {
+		assert_eq!(parse_floating_point("1.5.5"), None);
+		assert_eq!(parse_floating_point("1e5e5"), None);
+		assert_eq!(parse_floating_point("--1"), None);
+		assert_eq!(parse_floating_point("1\u{FF11}"), None);
+	}
+
+	#[test]
+	fn test_parse_floating_point_fails_overflow() {
+		assert_eq!(parse_floating_point("1e400"), None);
+		assert_eq!(parse_floating_point("-1e400"), None);
+		assert_eq!(parse_floating_point("1e-400"), Some(0.0));
+	}
+}
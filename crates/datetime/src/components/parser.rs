@@ -0,0 +1,330 @@
+use crate::components::timezone_offset::parse_timezone_offset_component_impl;
+use crate::tokens::Token;
+use crate::utils::ascii_char_at;
+use crate::{
+	parse_date_component, parse_month_component, parse_time_component,
+	parse_timezone_offset_component, parse_yearless_date_component, DelimiterPolicy,
+	TimeZoneOffset, YearMonth, YearlessDate,
+};
+use chrono::{NaiveDate, NaiveTime};
+
+/// A parser over a string, which keeps track of the current position
+/// while parsing one component after another
+///
+/// Each method parses a component at the current position, advancing past
+/// it on success. On failure, the position may have advanced partially
+/// into the component, and can be read with [`Parser::position`].
+///
+/// # Examples
+/// ```
+/// use chrono::{NaiveDate, NaiveTime};
+/// use whatwg_datetime::{DelimiterPolicy, Parser, TimeZoneOffset};
+///
+/// let mut p = Parser::new("2011-11-18T14:54-07:00");
+/// assert_eq!(p.date(), NaiveDate::from_ymd_opt(2011, 11, 18));
+/// assert_eq!(p.delimiter(DelimiterPolicy::Either), Some('T'));
+/// assert_eq!(p.time(), NaiveTime::from_hms_opt(14, 54, 0));
+/// assert_eq!(p.timezone_offset(), TimeZoneOffset::new_opt(-7, 0));
+/// assert_eq!(p.ensure_end(), Some(()));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Parser<'a> {
+	input: &'a str,
+	position: usize,
+}
+
+impl<'a> Parser<'a> {
+	/// Creates a new parser, starting at the beginning of the input
+	#[inline]
+	pub const fn new(input: &'a str) -> Self {
+		Self { input, position: 0 }
+	}
+
+	/// The input being parsed
+	#[inline]
+	pub const fn input(&self) -> &'a str {
+		self.input
+	}
+
+	/// The current position of the parser
+	#[inline]
+	pub const fn position(&self) -> usize {
+		self.position
+	}
+
+	/// Whether the parser has consumed the entire input
+	#[inline]
+	pub const fn is_at_end(&self) -> bool {
+		self.position >= self.input.len()
+	}
+
+	/// Requires that the parser has consumed the entire input
+	#[inline]
+	pub fn ensure_end(&self) -> Option<()> {
+		if self.is_at_end() {
+			Some(())
+		} else {
+			None
+		}
+	}
+
+	/// Parses a month component, see [`parse_month_component`]
+	#[inline]
+	pub fn month(&mut self) -> Option<YearMonth> {
+		parse_month_component(self.input, &mut self.position)
+	}
+
+	/// Parses a date component, see [`parse_date_component`]
+	#[inline]
+	pub fn date(&mut self) -> Option<NaiveDate> {
+		parse_date_component(self.input, &mut self.position)
+	}
+
+	/// Parses a yearless date component, see [`parse_yearless_date_component`]
+	#[inline]
+	pub fn yearless_date(&mut self) -> Option<YearlessDate> {
+		parse_yearless_date_component(self.input, &mut self.position)
+	}
+
+	/// Parses a time component, see [`parse_time_component`]
+	#[inline]
+	pub fn time(&mut self) -> Option<NaiveTime> {
+		parse_time_component(self.input, &mut self.position)
+	}
+
+	/// Parses a time-zone offset component, see [`parse_timezone_offset_component`]
+	#[inline]
+	pub fn timezone_offset(&mut self) -> Option<TimeZoneOffset> {
+		self.timezone_offset_impl(false)
+	}
+
+	/// Parses the delimiter between a date and a time, which must be
+	/// accepted by the given [`DelimiterPolicy`]
+	#[inline]
+	pub fn delimiter(&mut self, policy: DelimiterPolicy) -> Option<char> {
+		self.delimiter_impl(policy, false)
+	}
+
+	// If `lenient` is true, a lowercase `z` is also accepted as the UTC designator
+	pub(crate) fn timezone_offset_impl(&mut self, lenient: bool) -> Option<TimeZoneOffset> {
+		parse_timezone_offset_component_impl(self.input, &mut self.position, lenient)
+	}
+
+	// If `lenient` is true, a lowercase `t` is also accepted when `T` is
+	pub(crate) fn delimiter_impl(
+		&mut self,
+		policy: DelimiterPolicy,
+		lenient: bool,
+	) -> Option<char> {
+		match ascii_char_at(self.input, self.position) {
+			Some(c) if policy.accepts(c) => {
+				self.position += 1;
+				Some(c)
+			}
+			Some(c) if lenient
+				&& c == Token::T.to_ascii_lowercase()
+				&& policy.accepts(Token::T) =>
+			{
+				self.position += 1;
+				Some(c)
+			}
+			_ => None,
+		}
+	}
+}
+
+/// Parse a month component at the start of a string, advancing the
+/// string past it on success
+///
+/// This is an alternative to [`parse_month_component`] for composing parsers
+/// over a string slice, rather than a position. On failure, the string is
+/// left unchanged.
+///
+/// # Examples
+/// ```
+/// use whatwg_datetime::{take_month, YearMonth};
+///
+/// let mut input = "2011-11/2012-01";
+/// assert_eq!(take_month(&mut input), YearMonth::new_opt(2011, 11));
+/// assert_eq!(input, "/2012-01");
+/// ```
+#[inline]
+pub fn take_month(input: &mut &str) -> Option<YearMonth> {
+	take(input, parse_month_component)
+}
+
+/// Parse a date component at the start of a string, advancing the
+/// string past it on success
+///
+/// This is an alternative to [`parse_date_component`] for composing parsers
+/// over a string slice, rather than a position. On failure, the string is
+/// left unchanged.
+///
+/// # Examples
+/// ```
+/// use chrono::{NaiveDate, NaiveTime};
+/// use whatwg_datetime::{take_date, take_time};
+///
+/// let mut input = "2011-11-18 14:54";
+/// assert_eq!(take_date(&mut input), NaiveDate::from_ymd_opt(2011, 11, 18));
+/// assert_eq!(input, " 14:54");
+///
+/// input = &input[1..];
+/// assert_eq!(take_time(&mut input), NaiveTime::from_hms_opt(14, 54, 0));
+/// assert_eq!(input, "");
+/// ```
+#[inline]
+pub fn take_date(input: &mut &str) -> Option<NaiveDate> {
+	take(input, parse_date_component)
+}
+
+/// Parse a yearless date component at the start of a string, advancing
+/// the string past it on success
+///
+/// See [`take_date`] and [`parse_yearless_date_component`].
+#[inline]
+pub fn take_yearless_date(input: &mut &str) -> Option<YearlessDate> {
+	take(input, parse_yearless_date_component)
+}
+
+/// Parse a time component at the start of a string, advancing the
+/// string past it on success
+///
+/// See [`take_date`] and [`parse_time_component`].
+#[inline]
+pub fn take_time(input: &mut &str) -> Option<NaiveTime> {
+	take(input, parse_time_component)
+}
+
+/// Parse a time-zone offset component at the start of a string, advancing
+/// the string past it on success
+///
+/// See [`take_date`] and [`parse_timezone_offset_component`].
+#[inline]
+pub fn take_timezone_offset(input: &mut &str) -> Option<TimeZoneOffset> {
+	take(input, parse_timezone_offset_component)
+}
+
+fn take<T, F>(input: &mut &str, parse_fn: F) -> Option<T>
+where
+	F: FnOnce(&str, &mut usize) -> Option<T>,
+{
+	let mut position = 0usize;
+	let parsed = parse_fn(input, &mut position)?;
+	*input = input.get(position..)?;
+
+	Some(parsed)
+}
+
+#[cfg(test)]
+mod tests {
+	#[rustfmt::skip]
+	use super::{
+		take_date,
+		take_month,
+		take_time,
+		take_timezone_offset,
+		take_yearless_date,
+		Parser,
+	};
+	use crate::{DelimiterPolicy, TimeZoneOffset, YearMonth, YearlessDate};
+	use chrono::{NaiveDate, NaiveTime};
+
+	#[test]
+	fn test_parser_global_datetime() {
+		let mut p = Parser::new("2011-11-18 14:54:39.929Z");
+		assert_eq!(p.date(), NaiveDate::from_ymd_opt(2011, 11, 18));
+		assert_eq!(p.position(), 10);
+		assert_eq!(p.delimiter(DelimiterPolicy::Either), Some(' '));
+		assert_eq!(p.time(), NaiveTime::from_hms_milli_opt(14, 54, 39, 929));
+		assert!(!p.is_at_end());
+		assert_eq!(p.timezone_offset(), TimeZoneOffset::new_opt(0, 0));
+		assert!(p.is_at_end());
+		assert_eq!(p.ensure_end(), Some(()));
+	}
+
+	#[test]
+	fn test_parser_month() {
+		let mut p = Parser::new("2011-11");
+		assert_eq!(p.month(), YearMonth::new_opt(2011, 11));
+		assert_eq!(p.ensure_end(), Some(()));
+	}
+
+	#[test]
+	fn test_parser_yearless_date() {
+		let mut p = Parser::new("--11-18");
+		assert_eq!(p.yearless_date(), YearlessDate::new_opt(11, 18));
+		assert_eq!(p.ensure_end(), Some(()));
+	}
+
+	#[test]
+	fn test_parser_fails_trailing_characters() {
+		let mut p = Parser::new("2011-11-18garbage");
+		assert_eq!(p.date(), NaiveDate::from_ymd_opt(2011, 11, 18));
+		assert_eq!(p.ensure_end(), None);
+		assert_eq!(p.position(), 10);
+	}
+
+	#[test]
+	fn test_parser_fails_delimiter() {
+		let mut p = Parser::new("2011-11-18 14:54");
+		assert!(p.date().is_some());
+		assert_eq!(p.delimiter(DelimiterPolicy::TOnly), None);
+		assert_eq!(p.position(), 10);
+	}
+
+	#[test]
+	fn test_parser_delimiter_lenient() {
+		let mut p = Parser::new("t");
+		assert_eq!(p.delimiter_impl(DelimiterPolicy::Either, true), Some('t'));
+
+		let mut p = Parser::new("t");
+		assert_eq!(p.delimiter_impl(DelimiterPolicy::SpaceOnly, true), None);
+	}
+
+	#[test]
+	fn test_parser_input() {
+		let p = Parser::new("14:54");
+		assert_eq!(p.input(), "14:54");
+		assert_eq!(p.position(), 0);
+	}
+
+	#[test]
+	fn test_take_date_then_time() {
+		let mut input = "2011-11-18T14:54:39Z";
+		assert_eq!(take_date(&mut input), NaiveDate::from_ymd_opt(2011, 11, 18));
+		assert_eq!(input, "T14:54:39Z");
+
+		input = &input[1..];
+		assert_eq!(take_time(&mut input), NaiveTime::from_hms_opt(14, 54, 39));
+		assert_eq!(input, "Z");
+
+		assert_eq!(
+			take_timezone_offset(&mut input),
+			TimeZoneOffset::new_opt(0, 0)
+		);
+		assert_eq!(input, "");
+	}
+
+	#[test]
+	fn test_take_concatenated() {
+		let mut input = "2011-11--11-18";
+		assert_eq!(take_month(&mut input), YearMonth::new_opt(2011, 11));
+		assert_eq!(
+			take_yearless_date(&mut input),
+			YearlessDate::new_opt(11, 18)
+		);
+		assert_eq!(input, "");
+	}
+
+	#[test]
+	fn test_take_fails_leaves_input() {
+		let mut input = "2011-13-18";
+		assert_eq!(take_date(&mut input), None);
+		assert_eq!(input, "2011-13-18");
+
+		let mut input = "";
+		assert_eq!(take_time(&mut input), None);
+		assert_eq!(input, "");
+	}
+}
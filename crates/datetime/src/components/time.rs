@@ -1,8 +1,10 @@
 use crate::parse_format;
 use crate::tokens::Token;
-use crate::utils::{collect_ascii_digits, is_valid_hour, is_valid_min_or_sec};
-use chrono::NaiveTime;
-use whatwg_infra::collect_codepoints;
+use crate::utils::{
+	ascii_char_at, collect_ascii_digits, collect_integer, is_valid_hour, is_valid_min_or_sec,
+};
+use chrono::{NaiveTime, Timelike};
+use whatwg_infra::trim_ascii_whitespace;
 
 /// Parse a specific time containing an hour, minute, and optionally a second,
 /// and a fraction of a second
@@ -32,6 +34,12 @@ pub fn parse_time(s: &str) -> Option<NaiveTime> {
 	parse_format(s, parse_time_component)
 }
 
+/// Parse a string like [`parse_time`], after stripping leading and trailing ASCII whitespace
+#[inline]
+pub fn parse_time_trimmed(s: &str) -> Option<NaiveTime> {
+	parse_time(trim_ascii_whitespace(s))
+}
+
 /// Low-level function for parsing an individual time component at a given position
 ///
 /// This follows the rules for [parsing a time component][whatwg-html-parse]
@@ -55,17 +63,382 @@ pub fn parse_time(s: &str) -> Option<NaiveTime> {
 /// [whatwg-html-time]: https://html.spec.whatwg.org/multipage/common-microsyntaxes.html#times
 /// [whatwg-html-parse]: https://html.spec.whatwg.org/multipage/common-microsyntaxes.html#parse-a-time-component
 pub fn parse_time_component(s: &str, position: &mut usize) -> Option<NaiveTime> {
-	let parsed_hour = collect_ascii_digits(s, position);
-	if parsed_hour.len() != 2 {
+	parse_time_component_with_precision(s, position).map(|(time, _)| time)
+}
+
+/// Parse a time string, also returning the number of fractional-second
+/// digits that were parsed
+///
+/// This behaves the same as [`parse_time`], and is useful for serializing
+/// a time back with the exact precision it was written with. The precision
+/// is 0 if the seconds or fraction of a second were omitted, and saturates
+/// at [`u8::MAX`].
+///
+/// # Examples
+/// ```
+/// use chrono::NaiveTime;
+/// use whatwg_datetime::parse_time_with_precision;
+///
+/// assert_eq!(
+///     parse_time_with_precision("14:54:39.900"),
+///     Some((NaiveTime::from_hms_milli_opt(14, 54, 39, 900).unwrap(), 3))
+/// );
+/// assert_eq!(
+///     parse_time_with_precision("14:54:39.9"),
+///     Some((NaiveTime::from_hms_milli_opt(14, 54, 39, 900).unwrap(), 1))
+/// );
+/// ```
+#[inline]
+pub fn parse_time_with_precision(s: &str) -> Option<(NaiveTime, u8)> {
+	parse_format(s, parse_time_component_with_precision)
+		.map(|(time, precision)| (time, precision.unwrap_or(0)))
+}
+
+/// A parsed time, along with which of its optional components were present
+///
+/// This is returned by [`parse_time_detailed`], to distinguish between
+/// times such as `14:54` and `14:54:00` which [`parse_time`] treats the same.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TimeParse {
+	pub(crate) time: NaiveTime,
+	pub(crate) has_seconds: bool,
+	pub(crate) has_fraction: bool,
+}
+
+impl TimeParse {
+	#[inline]
+	pub(crate) const fn new(time: NaiveTime, has_seconds: bool, has_fraction: bool) -> Self {
+		Self {
+			time,
+			has_seconds,
+			has_fraction,
+		}
+	}
+
+	/// The parsed time
+	#[inline]
+	pub const fn time(&self) -> NaiveTime {
+		self.time
+	}
+
+	/// Whether the seconds were present, even if they were zero
+	#[inline]
+	pub const fn has_seconds(&self) -> bool {
+		self.has_seconds
+	}
+
+	/// Whether a fraction of a second was present, even if it was zero
+	#[inline]
+	pub const fn has_fraction(&self) -> bool {
+		self.has_fraction
+	}
+}
+
+/// A parsed time, along with exactly how it was written
+///
+/// This is returned by [`parse_time_exact`], and unlike [`TimeParse`], also
+/// keeps the number of fractional-second digits, so that the original string
+/// can be reproduced with [`ParsedTime::serialize`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ParsedTime {
+	pub(crate) time: NaiveTime,
+	pub(crate) has_seconds: bool,
+	pub(crate) fractional_digits: Option<u8>,
+}
+
+impl ParsedTime {
+	#[inline]
+	pub(crate) const fn new(
+		time: NaiveTime,
+		has_seconds: bool,
+		fractional_digits: Option<u8>,
+	) -> Self {
+		Self {
+			time,
+			has_seconds,
+			fractional_digits,
+		}
+	}
+
+	/// The parsed time
+	#[inline]
+	pub const fn time(&self) -> NaiveTime {
+		self.time
+	}
+
+	/// Whether the seconds were present, even if they were zero
+	#[inline]
+	pub const fn has_seconds(&self) -> bool {
+		self.has_seconds
+	}
+
+	/// The number of fractional-second digits, or [`None`] if there was
+	/// no fraction of a second
+	#[inline]
+	pub const fn fractional_digits(&self) -> Option<u8> {
+		self.fractional_digits
+	}
+
+	/// Serializes the time in the same form it was parsed from
+	///
+	/// Digits past nanosecond precision can't be represented, so they are
+	/// serialized as zeros.
+	///
+	/// # Examples
+	/// ```
+	/// use whatwg_datetime::parse_time_exact;
+	///
+	/// assert_eq!(parse_time_exact("14:54").unwrap().serialize(), "14:54");
+	/// assert_eq!(parse_time_exact("14:54:39").unwrap().serialize(), "14:54:39");
+	/// assert_eq!(parse_time_exact("14:54:39.000").unwrap().serialize(), "14:54:39.000");
+	/// ```
+	pub fn serialize(&self) -> String {
+		let mut s = format!(
+			"{:02}{}{:02}",
+			self.time.hour(),
+			Token::COLON,
+			self.time.minute()
+		);
+
+		if self.has_seconds {
+			s.push_str(&format!("{}{:02}", Token::COLON, self.time.second()));
+		}
+		if let Some(digits) = self.fractional_digits {
+			let fraction = format!("{:09}", self.time.nanosecond());
+			s.push(Token::DOT);
+			s.push_str(&format!(
+				"{:0<width$.width$}",
+				fraction,
+				width = digits as usize
+			));
+		}
+
+		s
+	}
+}
+
+/// Parse a time string, keeping exactly how it was written
+///
+/// This behaves the same as [`parse_time`], but distinguishes between
+/// times such as `14:54`, `14:54:00`, and `14:54:00.000`, which can be
+/// serialized back with [`ParsedTime::serialize`].
+///
+/// # Examples
+/// ```
+/// use whatwg_datetime::parse_time_exact;
+///
+/// let parsed = parse_time_exact("14:54:39.900").unwrap();
+/// assert_eq!(parsed.fractional_digits(), Some(3));
+/// assert_eq!(parsed.serialize(), "14:54:39.900");
+///
+/// let parsed = parse_time_exact("14:54:39").unwrap();
+/// assert_eq!(parsed.fractional_digits(), None);
+/// ```
+pub fn parse_time_exact(s: &str) -> Option<ParsedTime> {
+	let (time, precision) = parse_format(s, parse_time_component_with_precision)?;
+	Some(ParsedTime::new(
+		time,
+		precision.is_some(),
+		precision.filter(|&precision| precision > 0),
+	))
+}
+
+/// Parse a time on a 12-hour clock, such as `2:30 PM`
+///
+/// The hour is one or two digits from 1 to 12, followed by a colon, two
+/// digits for the minutes, and optionally a colon and two digits for the
+/// seconds. This is followed by an optional U+0020 SPACE, and then `AM` or
+/// `PM`, case-insensitively. `12 AM` is midnight, and `12 PM` is noon.
+///
+/// Note that this is not a valid time string per the WHATWG HTML Standard,
+/// and is useful for importing times which were entered by hand.
+///
+/// # Examples
+/// ```
+/// use chrono::NaiveTime;
+/// use whatwg_datetime::parse_time_12h;
+///
+/// assert_eq!(parse_time_12h("2:30 PM"), NaiveTime::from_hms_opt(14, 30, 0));
+/// assert_eq!(parse_time_12h("12:00am"), NaiveTime::from_hms_opt(0, 0, 0));
+/// assert_eq!(parse_time_12h("13:00 PM"), None);
+/// ```
+pub fn parse_time_12h(s: &str) -> Option<NaiveTime> {
+	let mut position = 0usize;
+	let (hour, _) = collect_integer(s, &mut position, 1, 2, true)?;
+	if !(1..=12).contains(&hour) || ascii_char_at(s, position) != Some(Token::COLON) {
+		return None;
+	}
+	position += 1;
+
+	let (minute, _) = collect_integer(s, &mut position, 2, 2, true)?;
+	if !is_valid_min_or_sec(&minute) {
 		return None;
 	}
 
-	let hour = parsed_hour.parse::<u32>().ok()?;
+	let mut second = 0u32;
+	if ascii_char_at(s, position) == Some(Token::COLON) {
+		position += 1;
+		second = collect_integer(s, &mut position, 2, 2, true)?.0;
+		if !is_valid_min_or_sec(&second) {
+			return None;
+		}
+	}
+
+	if ascii_char_at(s, position) == Some(Token::SPACE) {
+		position += 1;
+	}
+
+	let meridiem = s.get(position..)?;
+	let hour = if meridiem.eq_ignore_ascii_case("am") {
+		hour % 12
+	} else if meridiem.eq_ignore_ascii_case("pm") {
+		hour % 12 + 12
+	} else {
+		return None;
+	};
+
+	NaiveTime::from_hms_opt(hour, minute, second)
+}
+
+/// Parse a time string, also accepting a leap second
+///
+/// This behaves the same as [`parse_time`], except that the seconds may
+/// also be `60`, which some producers emit for a leap second. Note that
+/// this is not a valid time string per the WHATWG HTML Standard.
+///
+/// A leap second is represented the same way as in [`chrono`], as the 59th
+/// second with an extra second's worth of nanoseconds, so `23:59:60.5`
+/// parses to `23:59:59` with 1,500,000,000 nanoseconds. This keeps it
+/// ordered after `23:59:59.999` and before the next minute.
+///
+/// # Examples
+/// ```
+/// use chrono::{NaiveTime, Timelike};
+/// use whatwg_datetime::{parse_time, parse_time_allow_leap_second};
+///
+/// let time = parse_time_allow_leap_second("23:59:60").unwrap();
+/// assert_eq!(time, NaiveTime::from_hms_milli_opt(23, 59, 59, 1_000).unwrap());
+/// assert_eq!(time.second(), 59);
+/// assert_eq!(parse_time("23:59:60"), None);
+/// ```
+pub fn parse_time_allow_leap_second(s: &str) -> Option<NaiveTime> {
+	let ((hour, minute, seconds, nanoseconds), _) = parse_format(s, |s, position| {
+		parse_time_parts_component_impl(s, position, true, false)
+	})?;
+
+	if seconds == 60 {
+		NaiveTime::from_hms_nano_opt(hour, minute, 59, 1_000_000_000 + nanoseconds)
+	} else {
+		NaiveTime::from_hms_nano_opt(hour, minute, seconds, nanoseconds)
+	}
+}
+
+/// Parse a time string, also accepting an hour without a leading zero
+///
+/// This behaves the same as [`parse_time`], except that the hour may also
+/// be a single digit, e.g `9:30`, which is common in human input. The hour
+/// is still at most two digits. Note that this is not a valid time string
+/// per the WHATWG HTML Standard.
+///
+/// # Examples
+/// ```
+/// use chrono::NaiveTime;
+/// use whatwg_datetime::{parse_time, parse_time_lenient};
+///
+/// assert_eq!(parse_time_lenient("9:30"), NaiveTime::from_hms_opt(9, 30, 0));
+/// assert_eq!(parse_time_lenient("09:30"), NaiveTime::from_hms_opt(9, 30, 0));
+/// assert_eq!(parse_time_lenient("009:30"), None);
+/// assert_eq!(parse_time("9:30"), None);
+/// ```
+pub fn parse_time_lenient(s: &str) -> Option<NaiveTime> {
+	let ((hour, minute, seconds, nanoseconds), _) = parse_format(s, |s, position| {
+		parse_time_parts_component_impl(s, position, false, true)
+	})?;
+
+	NaiveTime::from_hms_nano_opt(hour, minute, seconds, nanoseconds)
+}
+
+/// Parse a time string, also returning whether the seconds and a fraction
+/// of a second were present
+///
+/// # Examples
+/// ```
+/// use chrono::NaiveTime;
+/// use whatwg_datetime::parse_time_detailed;
+///
+/// let parsed = parse_time_detailed("14:54:00").unwrap();
+/// assert_eq!(parsed.time(), NaiveTime::from_hms_opt(14, 54, 0).unwrap());
+/// assert!(parsed.has_seconds());
+/// assert!(!parsed.has_fraction());
+///
+/// let parsed = parse_time_detailed("14:54").unwrap();
+/// assert!(!parsed.has_seconds());
+/// ```
+pub fn parse_time_detailed(s: &str) -> Option<TimeParse> {
+	let (time, precision) = parse_format(s, parse_time_component_with_precision)?;
+	Some(TimeParse::new(
+		time,
+		precision.is_some(),
+		precision.is_some_and(|precision| precision > 0),
+	))
+}
+
+/// Parse a [time][whatwg-html-times] into its raw components, without
+/// constructing a [`NaiveTime`]
+///
+/// This returns a tuple of the hour, minute, second, and nanosecond,
+/// each of which has already been validated. A fraction of a second is
+/// truncated to nanosecond precision.
+///
+/// # Examples
+/// ```
+/// use whatwg_datetime::parse_time_parts;
+///
+/// assert_eq!(parse_time_parts("14:54:39.929"), Some((14, 54, 39, 929_000_000)));
+/// assert_eq!(parse_time_parts("14:54"), Some((14, 54, 0, 0)));
+/// assert_eq!(parse_time_parts("24:00"), None);
+/// ```
+///
+/// [whatwg-html-times]: https://html.spec.whatwg.org/multipage/common-microsyntaxes.html#times
+#[inline]
+pub fn parse_time_parts(s: &str) -> Option<(u32, u32, u32, u32)> {
+	parse_format(s, parse_time_parts_component).map(|(parts, _)| parts)
+}
+
+// The precision is `None` if the seconds were omitted
+fn parse_time_component_with_precision(
+	s: &str,
+	position: &mut usize,
+) -> Option<(NaiveTime, Option<u8>)> {
+	let ((hour, minute, seconds, nanoseconds), precision) =
+		parse_time_parts_component(s, position)?;
+	let time = NaiveTime::from_hms_nano_opt(hour, minute, seconds, nanoseconds)?;
+	Some((time, precision))
+}
+
+// The hour, minute, second, and nanosecond of a time
+type TimeParts = (u32, u32, u32, u32);
+
+#[inline]
+fn parse_time_parts_component(s: &str, position: &mut usize) -> Option<(TimeParts, Option<u8>)> {
+	parse_time_parts_component_impl(s, position, false, false)
+}
+
+// If `allow_leap_second` is true, a seconds value of 60 is also accepted,
+// and if `lenient_hour` is true, the hour may also be a single digit
+fn parse_time_parts_component_impl(
+	s: &str,
+	position: &mut usize,
+	allow_leap_second: bool,
+	lenient_hour: bool,
+) -> Option<(TimeParts, Option<u8>)> {
+	let min_hour_digits = if lenient_hour { 1 } else { 2 };
+	let (hour, _) = collect_integer(s, position, min_hour_digits, 2, true)?;
 	if !is_valid_hour(&hour) {
 		return None;
 	}
 
-	if *position > s.len() || s.chars().nth(*position) != Some(Token::COLON) {
+	if ascii_char_at(s, *position) != Some(Token::COLON) {
 		return None;
 	} else {
 		*position += 1;
@@ -81,61 +454,336 @@ pub fn parse_time_component(s: &str, position: &mut usize) -> Option<NaiveTime>
 	}
 
 	let mut seconds = 0u32;
-	let mut milliseconds = 0u32;
-	if *position < s.len() && s.chars().nth(*position) == Some(Token::COLON) {
+	let mut nanoseconds = 0u32;
+	let mut precision = None;
+	if ascii_char_at(s, *position) == Some(Token::COLON) {
 		*position += 1;
 
 		if *position >= s.len() {
 			return None;
 		}
 
-		let parsed_second =
-			collect_codepoints(s, position, |c| c.is_ascii_digit() || c == Token::DOT);
+		// Collect the digits and dots of the seconds in a single pass,
+		// failing as soon as a second dot is found
+		let start = *position;
+		let mut has_dot = false;
+		while let Some(c) = ascii_char_at(s, *position) {
+			if c == Token::DOT {
+				if has_dot {
+					return None;
+				}
+				has_dot = true;
+			} else if !c.is_ascii_digit() {
+				break;
+			}
+			*position += 1;
+		}
+
+		let parsed_second = &s[start..*position];
 		let parsed_second_len = parsed_second.len();
 		if parsed_second_len == 3
 			|| (parsed_second_len > 3
-				&& parsed_second.chars().nth(2) != Some(Token::DOT))
-			|| has_at_least_n_instances(s, Token::DOT, 2)
+				&& ascii_char_at(parsed_second, 2) != Some(Token::DOT))
 		{
 			return None;
 		}
 
-		let (parsed_seconds, parsed_milliseconds) =
-			parse_seconds_milliseconds(&parsed_second);
+		let (parsed_seconds, fraction) = split_seconds_fraction(parsed_second);
 		seconds = parsed_seconds;
-		milliseconds = parsed_milliseconds;
-		if !is_valid_min_or_sec(&seconds) {
+		nanoseconds = parse_fraction_as_nanoseconds(fraction);
+		precision = Some(fraction.len().min(u8::MAX as usize) as u8);
+		if !(is_valid_min_or_sec(&seconds) || (allow_leap_second && seconds == 60)) {
 			return None;
 		}
 	}
 
-	NaiveTime::from_hms_milli_opt(hour, minute, seconds, milliseconds)
+	Some(((hour, minute, seconds, nanoseconds), precision))
 }
 
-fn has_at_least_n_instances(s: &str, c: char, n: usize) -> bool {
-	let mut count = 0usize;
-	for ch in s.chars() {
-		if ch == c {
-			count += 1usize;
-			if count >= n {
-				return true;
-			}
-		}
+/// Checks if a string is a valid time string in its shortest canonical form
+///
+/// A time string is in its shortest form if the seconds are omitted when
+/// the time is zero seconds past the minute, and if the fractional seconds
+/// have no trailing zeros. This is the form used for the time in a
+/// [valid normalized local date and time string][whatwg-html-normalized].
+///
+/// # Examples
+/// ```
+/// use whatwg_datetime::{is_normalized_time_string, parse_time};
+///
+/// assert!(is_normalized_time_string("14:54"));
+/// assert!(is_normalized_time_string("14:54:39.9"));
+///
+/// // still valid time strings, but not in their shortest form
+/// assert!(parse_time("14:54:00").is_some());
+/// assert!(!is_normalized_time_string("14:54:00"));
+/// assert!(!is_normalized_time_string("14:54:39.900"));
+/// ```
+///
+/// [whatwg-html-normalized]: https://html.spec.whatwg.org/multipage/common-microsyntaxes.html#valid-normalised-local-date-and-time-string
+pub fn is_normalized_time_string(s: &str) -> bool {
+	if parse_time(s).is_none() {
+		return false;
+	}
+
+	// A successfully parsed time always starts with `HH:MM`,
+	// optionally followed by a colon and the seconds
+	let seconds = match s.get(5..) {
+		Some("") => return true,
+		Some(rest) => &rest[1..],
+		None => return false,
+	};
+
+	match seconds.split_once(Token::DOT) {
+		Some((_, fraction)) => !fraction.is_empty() && !fraction.ends_with('0'),
+		None => seconds != "00",
 	}
-	false
 }
 
-fn parse_seconds_milliseconds(s: &str) -> (u32, u32) {
-	let parts: Vec<&str> = s.split(Token::DOT).collect();
-	let seconds = parts.first().unwrap_or(&"0").parse().unwrap_or(0);
-	let milliseconds = parts.get(1).unwrap_or(&"0").parse().unwrap_or(0);
+fn split_seconds_fraction(s: &str) -> (u32, &str) {
+	let (seconds, fraction) = s.split_once(Token::DOT).unwrap_or((s, ""));
+	(seconds.parse().unwrap_or(0), fraction)
+}
+
+// Digits past nanosecond precision are truncated, since they can't be represented
+fn parse_fraction_as_nanoseconds(fraction: &str) -> u32 {
+	let mut nanoseconds = 0u32;
+	let mut digits = fraction.bytes();
+	for _ in 0..9 {
+		let digit = match digits.next() {
+			Some(b) => (b - b'0') as u32,
+			None => 0,
+		};
+		nanoseconds = nanoseconds * 10 + digit;
+	}
 
-	(seconds, milliseconds)
+	nanoseconds
 }
 
 #[cfg(test)]
 mod tests {
-	use super::{parse_time, parse_time_component, NaiveTime};
+	#[rustfmt::skip]
+	use super::{
+		is_normalized_time_string,
+		parse_time,
+		parse_time_12h,
+		parse_time_allow_leap_second,
+		parse_time_component,
+		parse_time_detailed,
+		parse_time_exact,
+		parse_time_lenient,
+		parse_time_parts,
+		parse_time_with_precision,
+		NaiveTime,
+	};
+
+	#[test]
+	fn test_parse_time_12h() {
+		assert_eq!(
+			parse_time_12h("2:30 PM"),
+			NaiveTime::from_hms_opt(14, 30, 0)
+		);
+		assert_eq!(
+			parse_time_12h("02:30 pm"),
+			NaiveTime::from_hms_opt(14, 30, 0)
+		);
+		assert_eq!(parse_time_12h("2:30PM"), NaiveTime::from_hms_opt(14, 30, 0));
+		assert_eq!(
+			parse_time_12h("9:05:07 aM"),
+			NaiveTime::from_hms_opt(9, 5, 7)
+		);
+		assert_eq!(
+			parse_time_12h("11:59 PM"),
+			NaiveTime::from_hms_opt(23, 59, 0)
+		);
+	}
+
+	#[test]
+	fn test_parse_time_12h_midnight() {
+		assert_eq!(parse_time_12h("12:00 AM"), NaiveTime::from_hms_opt(0, 0, 0));
+		assert_eq!(
+			parse_time_12h("12:30 AM"),
+			NaiveTime::from_hms_opt(0, 30, 0)
+		);
+	}
+
+	#[test]
+	fn test_parse_time_12h_noon() {
+		assert_eq!(
+			parse_time_12h("12:00 PM"),
+			NaiveTime::from_hms_opt(12, 0, 0)
+		);
+		assert_eq!(
+			parse_time_12h("12:30 PM"),
+			NaiveTime::from_hms_opt(12, 30, 0)
+		);
+	}
+
+	#[test]
+	fn test_parse_time_12h_fails() {
+		for s in [
+			"13:00 PM",
+			"0:30 AM",
+			"00:30 AM",
+			"2:30",
+			"2:3 PM",
+			"2:60 PM",
+			"2:30:60 PM",
+			"2:30  PM",
+			"2:30 P.M.",
+			"2:30 PMx",
+			" 2:30 PM",
+			"2 PM",
+			"123:00 PM",
+		] {
+			assert_eq!(parse_time_12h(s), None, "{:?}", s);
+		}
+	}
+
+	#[test]
+	fn test_parse_time_fails_12h() {
+		assert_eq!(parse_time("2:30 PM"), None);
+		assert_eq!(parse_time("02:30 PM"), None);
+	}
+
+	#[test]
+	fn test_parse_time_allow_leap_second() {
+		assert_eq!(
+			parse_time_allow_leap_second("23:59:60"),
+			NaiveTime::from_hms_milli_opt(23, 59, 59, 1_000)
+		);
+		assert_eq!(
+			parse_time_allow_leap_second("23:59:60.25"),
+			NaiveTime::from_hms_milli_opt(23, 59, 59, 1_250)
+		);
+		assert_eq!(
+			parse_time_allow_leap_second("14:54:39"),
+			NaiveTime::from_hms_opt(14, 54, 39)
+		);
+	}
+
+	#[test]
+	fn test_parse_time_allow_leap_second_ordering() {
+		let before = parse_time_allow_leap_second("23:59:59.999").unwrap();
+		let leap = parse_time_allow_leap_second("23:59:60").unwrap();
+		assert!(before < leap);
+	}
+
+	#[test]
+	fn test_parse_time_allow_leap_second_fails() {
+		assert_eq!(parse_time("23:59:60"), None);
+		assert_eq!(parse_time_allow_leap_second("23:59:61"), None);
+		assert_eq!(parse_time_allow_leap_second("23:60:00"), None);
+	}
+
+	#[test]
+	fn test_parse_time_exact_round_trip() {
+		for s in [
+			"14:54",
+			"14:54:00",
+			"14:54:39",
+			"14:54:00.000",
+			"14:54:39.9",
+			"14:54:39.900",
+			"14:54:39.000000001",
+		] {
+			assert_eq!(parse_time_exact(s).unwrap().serialize(), s);
+		}
+	}
+
+	#[test]
+	fn test_parse_time_exact_fractional_digits() {
+		assert_eq!(parse_time_exact("14:54").unwrap().fractional_digits(), None);
+		assert_eq!(
+			parse_time_exact("14:54:39").unwrap().fractional_digits(),
+			None
+		);
+		assert_eq!(
+			parse_time_exact("14:54:39.000")
+				.unwrap()
+				.fractional_digits(),
+			Some(3)
+		);
+		assert!(!parse_time_exact("14:54").unwrap().has_seconds());
+		assert!(parse_time_exact("14:54:00").unwrap().has_seconds());
+	}
+
+	#[test]
+	fn test_parse_time_exact_same_time() {
+		let times = ["14:54:39", "14:54:39.0", "14:54:39.000"]
+			.map(|s| parse_time_exact(s).unwrap());
+		assert!(times.iter().all(|parsed| parsed.time() == times[0].time()));
+		assert_ne!(times[0], times[1]);
+		assert_ne!(times[1], times[2]);
+	}
+
+	#[test]
+	fn test_parse_time_exact_serialize_past_nanoseconds() {
+		assert_eq!(
+			parse_time_exact("14:54:39.1234567891").unwrap().serialize(),
+			"14:54:39.1234567890"
+		);
+	}
+
+	#[test]
+	fn test_parse_time_exact_fails() {
+		assert_eq!(parse_time_exact("14:54:"), None);
+		assert_eq!(parse_time_exact("14:54:39."), None);
+	}
+
+	#[test]
+	fn test_parse_time_detailed_hm() {
+		let parsed = parse_time_detailed("14:54").unwrap();
+		assert_eq!(parsed.time(), NaiveTime::from_hms_opt(14, 54, 0).unwrap());
+		assert!(!parsed.has_seconds());
+		assert!(!parsed.has_fraction());
+	}
+
+	#[test]
+	fn test_parse_time_detailed_hms() {
+		let parsed = parse_time_detailed("14:54:00").unwrap();
+		assert_eq!(parsed.time(), NaiveTime::from_hms_opt(14, 54, 0).unwrap());
+		assert!(parsed.has_seconds());
+		assert!(!parsed.has_fraction());
+	}
+
+	#[test]
+	fn test_parse_time_detailed_hms_fraction() {
+		let parsed = parse_time_detailed("14:54:00.000").unwrap();
+		assert_eq!(parsed.time(), NaiveTime::from_hms_opt(14, 54, 0).unwrap());
+		assert!(parsed.has_seconds());
+		assert!(parsed.has_fraction());
+
+		let parsed = parse_time_detailed("14:54:39.929").unwrap();
+		assert_eq!(
+			parsed.time(),
+			NaiveTime::from_hms_milli_opt(14, 54, 39, 929).unwrap()
+		);
+		assert!(parsed.has_fraction());
+	}
+
+	#[test]
+	fn test_parse_time_detailed_fails() {
+		assert_eq!(parse_time_detailed("14:54:"), None);
+		assert_eq!(parse_time_detailed("14:54:00 "), None);
+	}
+
+	#[test]
+	fn test_parse_time_parts() {
+		assert_eq!(
+			parse_time_parts("14:54:39.929"),
+			Some((14, 54, 39, 929_000_000))
+		);
+		assert_eq!(parse_time_parts("00:00"), Some((0, 0, 0, 0)));
+		assert_eq!(parse_time_parts("23:59:59"), Some((23, 59, 59, 0)));
+	}
+
+	#[test]
+	fn test_parse_time_parts_fails() {
+		assert_eq!(parse_time_parts("24:00"), None);
+		assert_eq!(parse_time_parts("12:60"), None);
+		assert_eq!(parse_time_parts("12:31:59 "), None);
+	}
 
 	#[test]
 	fn test_parse_time_succeeds_hm() {
@@ -161,6 +809,50 @@ mod tests {
 		);
 	}
 
+	#[test]
+	fn test_parse_time_succeeds_hms_fractional_seconds_short() {
+		assert_eq!(
+			parse_time("14:54:39.9"),
+			NaiveTime::from_hms_milli_opt(14, 54, 39, 900)
+		);
+	}
+
+	#[test]
+	fn test_parse_time_succeeds_hms_fractional_seconds_long() {
+		assert_eq!(
+			parse_time("14:54:39.123456789123"),
+			NaiveTime::from_hms_nano_opt(14, 54, 39, 123_456_789)
+		);
+	}
+
+	#[test]
+	fn test_parse_time_with_precision_one_digit() {
+		assert_eq!(
+			parse_time_with_precision("14:54:39.9"),
+			Some((NaiveTime::from_hms_milli_opt(14, 54, 39, 900).unwrap(), 1))
+		);
+	}
+
+	#[test]
+	fn test_parse_time_with_precision_three_digits() {
+		assert_eq!(
+			parse_time_with_precision("14:54:39.900"),
+			Some((NaiveTime::from_hms_milli_opt(14, 54, 39, 900).unwrap(), 3))
+		);
+	}
+
+	#[test]
+	fn test_parse_time_with_precision_no_fraction() {
+		assert_eq!(
+			parse_time_with_precision("14:54:39"),
+			Some((NaiveTime::from_hms_opt(14, 54, 39).unwrap(), 0))
+		);
+		assert_eq!(
+			parse_time_with_precision("14:54"),
+			Some((NaiveTime::from_hms_opt(14, 54, 0).unwrap(), 0))
+		);
+	}
+
 	#[test]
 	fn test_parse_time_fails_multiple_decimals() {
 		assert_eq!(parse_time("12:31:59...29"), None);
@@ -181,6 +873,13 @@ mod tests {
 		assert_eq!(parse_time("12-31-59"), None);
 	}
 
+	#[test]
+	fn test_parse_time_fails_multibyte_content() {
+		assert_eq!(parse_time("12\u{2236}31"), None);
+		assert_eq!(parse_time("12:31\u{2236}59"), None);
+		assert_eq!(parse_time("12:31:59\u{e9}"), None);
+	}
+
 	#[test]
 	fn test_parse_time_fails_minute_length() {
 		assert_eq!(parse_time("12:311:59"), None);
@@ -201,6 +900,37 @@ mod tests {
 		assert_eq!(parse_time("12:31:79"), None);
 	}
 
+	#[test]
+	fn test_parse_time_fails_leading_sign() {
+		assert_eq!(parse_time("+14:54"), None);
+		assert_eq!(parse_time("-14:54"), None);
+	}
+
+	#[test]
+	fn test_is_normalized_time_string() {
+		assert!(is_normalized_time_string("14:54"));
+		assert!(is_normalized_time_string("14:54:39"));
+		assert!(is_normalized_time_string("14:54:00.5"));
+		assert!(is_normalized_time_string("14:54:39.929"));
+	}
+
+	#[test]
+	fn test_is_normalized_time_string_fails_zero_seconds() {
+		assert!(!is_normalized_time_string("14:54:00"));
+	}
+
+	#[test]
+	fn test_is_normalized_time_string_fails_trailing_zeros() {
+		assert!(!is_normalized_time_string("14:54:39.900"));
+		assert!(!is_normalized_time_string("14:54:39.0"));
+	}
+
+	#[test]
+	fn test_is_normalized_time_string_fails_invalid() {
+		assert!(!is_normalized_time_string("24:00"));
+		assert!(!is_normalized_time_string(""));
+	}
+
 	#[test]
 	fn test_parse_time_component() {
 		let mut position = 0usize;
@@ -208,4 +938,86 @@ mod tests {
 
 		assert_eq!(parsed, NaiveTime::from_hms_milli_opt(12, 31, 59, 0));
 	}
+
+	#[test]
+	fn test_parse_time_fails_two_dots_in_seconds() {
+		assert_eq!(parse_time("12:00:00.5.5"), None);
+		assert_eq!(parse_time("12:00:00..5"), None);
+
+		let mut position = 0usize;
+		assert_eq!(parse_time_component("12:00:00.5.5", &mut position), None);
+	}
+
+	#[test]
+	fn test_parse_time_component_ignores_dot_before_time() {
+		let mut position = 2usize;
+		assert_eq!(
+			parse_time_component("x.12:00:00.5", &mut position),
+			NaiveTime::from_hms_milli_opt(12, 0, 0, 500)
+		);
+		assert_eq!(position, 12);
+	}
+
+	#[test]
+	fn test_parse_time_component_stops_after_seconds() {
+		let mut position = 0usize;
+		assert_eq!(
+			parse_time_component("12:00:00.5Z", &mut position),
+			NaiveTime::from_hms_milli_opt(12, 0, 0, 500)
+		);
+		assert_eq!(position, 10);
+	}
+
+	#[test]
+	fn test_parse_time_component_multibyte_prefix() {
+		let mut position = 3usize;
+		let parsed = parse_time_component("\u{65E5}14:54", &mut position);
+
+		assert_eq!(parsed, NaiveTime::from_hms_opt(14, 54, 0));
+		assert_eq!(position, 8);
+	}
+
+	#[test]
+	fn test_parse_time_component_fails_not_char_boundary() {
+		let mut position = 1usize;
+		assert_eq!(parse_time_component("\u{65E5}14:54", &mut position), None);
+		assert_eq!(position, 1);
+	}
+
+	#[test]
+	fn test_parse_time_lenient() {
+		assert_eq!(
+			parse_time_lenient("9:30"),
+			NaiveTime::from_hms_opt(9, 30, 0)
+		);
+		assert_eq!(
+			parse_time_lenient("09:30"),
+			NaiveTime::from_hms_opt(9, 30, 0)
+		);
+		assert_eq!(parse_time_lenient("0:05"), NaiveTime::from_hms_opt(0, 5, 0));
+		assert_eq!(
+			parse_time_lenient("23:59"),
+			NaiveTime::from_hms_opt(23, 59, 0)
+		);
+		assert_eq!(
+			parse_time_lenient("9:30:15.5"),
+			NaiveTime::from_hms_milli_opt(9, 30, 15, 500)
+		);
+	}
+
+	#[test]
+	fn test_parse_time_lenient_fails() {
+		assert_eq!(parse_time_lenient("009:30"), None);
+		assert_eq!(parse_time_lenient("24:00"), None);
+		assert_eq!(parse_time_lenient(":30"), None);
+		assert_eq!(parse_time_lenient("9:3"), None);
+		assert_eq!(parse_time_lenient("9:30 "), None);
+	}
+
+	#[test]
+	fn test_parse_time_fails_single_digit_hour() {
+		assert_eq!(parse_time("9:30"), None);
+		assert_eq!(parse_time("009:30"), None);
+		assert_eq!(parse_time("09:30"), NaiveTime::from_hms_opt(9, 30, 0));
+	}
 }
@@ -1,6 +1,8 @@
 use crate::parse_format;
 use crate::tokens::Token;
-use crate::utils::collect_ascii_digits;
+use crate::utils::{ascii_char_at, collect_ascii_digits, collect_integer, is_valid_min_or_sec};
+use chrono::{Duration, FixedOffset};
+use whatwg_infra::trim_ascii_whitespace;
 
 /// A time-zone offset, with a signed number of hours and minutes.
 ///
@@ -10,16 +12,21 @@ use crate::utils::collect_ascii_digits;
 ///
 /// assert_eq!(parse_timezone_offset("-07:00"), TimeZoneOffset::new_opt(-7, 0));
 /// ```
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Clone, Copy)]
 pub struct TimeZoneOffset {
 	pub(crate) hour: i32,
 	pub(crate) minute: i32,
+	pub(crate) was_z: bool,
 }
 
 impl TimeZoneOffset {
 	#[inline]
 	pub(crate) fn new(hour: i32, minute: i32) -> Self {
-		Self { hour, minute }
+		Self {
+			hour,
+			minute,
+			was_z: false,
+		}
 	}
 
 	/// Creates a new `TimeZoneOffset` from a signed number of hours and minutes.
@@ -76,6 +83,249 @@ impl TimeZoneOffset {
 	pub const fn hour(&self) -> i32 {
 		self.hour
 	}
+
+	/// The signed difference between two time-zone offsets, in minutes.
+	///
+	/// This is useful for converting a time from one time-zone offset to
+	/// another, by adding the difference to the time.
+	///
+	/// # Examples
+	/// ```
+	/// use whatwg_datetime::parse_timezone_offset;
+	///
+	/// let ist = parse_timezone_offset("+05:30").unwrap();
+	/// let mst = parse_timezone_offset("-07:00").unwrap();
+	/// assert_eq!(ist.difference(&mst), 750);
+	/// assert_eq!(mst.difference(&ist), -750);
+	/// ```
+	#[inline]
+	pub const fn difference(&self, other: &TimeZoneOffset) -> i32 {
+		self.total_minutes() - other.total_minutes()
+	}
+
+	/// Whether the offset is a whole number of hours, e.g `+05:00`
+	///
+	/// # Examples
+	/// ```
+	/// use whatwg_datetime::parse_timezone_offset;
+	///
+	/// assert!(parse_timezone_offset("+05:00").unwrap().is_whole_hour());
+	/// assert!(!parse_timezone_offset("+05:30").unwrap().is_whole_hour());
+	/// ```
+	#[inline]
+	pub const fn is_whole_hour(&self) -> bool {
+		self.minute == 0
+	}
+
+	/// Whether the offset is a whole number of hours and a half, e.g `+05:30`
+	///
+	/// # Examples
+	/// ```
+	/// use whatwg_datetime::parse_timezone_offset;
+	///
+	/// assert!(parse_timezone_offset("+05:30").unwrap().is_half_hour());
+	/// assert!(parse_timezone_offset("-09:30").unwrap().is_half_hour());
+	/// assert!(!parse_timezone_offset("+05:00").unwrap().is_half_hour());
+	/// ```
+	#[inline]
+	pub const fn is_half_hour(&self) -> bool {
+		self.minute.abs() == 30
+	}
+
+	/// Whether the offset is a whole number of hours and a quarter or three
+	/// quarters, e.g `+05:45`
+	///
+	/// # Examples
+	/// ```
+	/// use whatwg_datetime::parse_timezone_offset;
+	///
+	/// assert!(parse_timezone_offset("+05:45").unwrap().is_quarter_hour());
+	/// assert!(parse_timezone_offset("+08:15").unwrap().is_quarter_hour());
+	/// assert!(!parse_timezone_offset("+05:30").unwrap().is_quarter_hour());
+	/// ```
+	#[inline]
+	pub const fn is_quarter_hour(&self) -> bool {
+		matches!(self.minute.abs(), 15 | 45)
+	}
+
+	/// Whether the offset was parsed from the `Z` designator, rather than
+	/// from a numeric offset such as `+00:00`
+	///
+	/// Both forms are the same offset, and compare as equal. This only
+	/// records which form was written, see [`TimeZoneOffset::to_string_original`].
+	///
+	/// # Examples
+	/// ```
+	/// use whatwg_datetime::parse_timezone_offset;
+	///
+	/// let z = parse_timezone_offset("Z").unwrap();
+	/// let zero = parse_timezone_offset("+00:00").unwrap();
+	/// assert_eq!(z, zero);
+	/// assert!(z.was_z());
+	/// assert!(!zero.was_z());
+	/// ```
+	#[inline]
+	pub const fn was_z(&self) -> bool {
+		self.was_z
+	}
+
+	/// Serializes the offset using the designator it was parsed from
+	///
+	/// This uses `Z` only if the offset was parsed from `Z`, and otherwise a
+	/// sign followed by zero-padded 2-digit hours and minutes separated by a
+	/// colon, e.g `+00:00`. Since an offset of zero has no sign, `-00:00`
+	/// is serialized as `+00:00`. See [`serialize_timezone_offset`] for the
+	/// best representation of the offset.
+	///
+	/// # Examples
+	/// ```
+	/// use whatwg_datetime::{parse_timezone_offset, serialize_timezone_offset};
+	///
+	/// let offset = parse_timezone_offset("+00:00").unwrap();
+	/// assert_eq!(offset.to_string_original(), "+00:00");
+	/// assert_eq!(serialize_timezone_offset(&offset), "Z");
+	///
+	/// let offset = parse_timezone_offset("Z").unwrap();
+	/// assert_eq!(offset.to_string_original(), "Z");
+	/// ```
+	pub fn to_string_original(&self) -> String {
+		if self.was_z {
+			return String::from(Token::Z);
+		}
+
+		format_timezone_offset(self, Some(Token::COLON), false)
+	}
+
+	/// Serializes the offset into its compact form, without a colon
+	/// between the hours and minutes, e.g `-0700`.
+	///
+	/// An offset of zero is serialized as `Z`. See [`serialize_timezone_offset`]
+	/// for the form with a colon, which is the best representation.
+	///
+	/// # Examples
+	/// ```
+	/// use whatwg_datetime::{parse_timezone_offset, serialize_timezone_offset};
+	///
+	/// let offset = parse_timezone_offset("-07:00").unwrap();
+	/// assert_eq!(offset.to_string_compact(), "-0700");
+	/// assert_eq!(serialize_timezone_offset(&offset), "-07:00");
+	///
+	/// let offset = parse_timezone_offset("+00:00").unwrap();
+	/// assert_eq!(offset.to_string_compact(), "Z");
+	/// ```
+	pub fn to_string_compact(&self) -> String {
+		format_timezone_offset(self, None, true)
+	}
+
+	#[inline]
+	pub(crate) const fn total_minutes(&self) -> i32 {
+		self.hour * 60 + self.minute
+	}
+}
+
+impl PartialEq for TimeZoneOffset {
+	fn eq(&self, other: &Self) -> bool {
+		self.hour == other.hour && self.minute == other.minute
+	}
+}
+
+impl Eq for TimeZoneOffset {}
+
+impl TryFrom<FixedOffset> for TimeZoneOffset {
+	type Error = ();
+
+	/// Converts a [`chrono::FixedOffset`] into a `TimeZoneOffset`.
+	///
+	/// This fails if the offset is not a whole number of minutes, since
+	/// time-zone offsets only have a granularity of minutes, or if the
+	/// hours are not between -23 and 23, inclusive.
+	///
+	/// # Examples
+	/// ```
+	/// use chrono::FixedOffset;
+	/// use whatwg_datetime::{parse_timezone_offset, TimeZoneOffset};
+	///
+	/// let fixed_offset = FixedOffset::east_opt(5 * 3600 + 30 * 60).unwrap();
+	/// assert_eq!(
+	///     TimeZoneOffset::try_from(fixed_offset).ok(),
+	///     parse_timezone_offset("+05:30")
+	/// );
+	/// ```
+	fn try_from(value: FixedOffset) -> Result<Self, Self::Error> {
+		let seconds = value.local_minus_utc();
+		if seconds % 60 != 0 {
+			return Err(());
+		}
+
+		let minutes = seconds / 60;
+		let hours = minutes / 60;
+		if !(-23..=23).contains(&hours) {
+			return Err(());
+		}
+
+		Ok(Self::new(hours, minutes % 60))
+	}
+}
+
+impl From<TimeZoneOffset> for Duration {
+	/// Converts a `TimeZoneOffset` into the signed [`chrono::Duration`] it
+	/// represents, which is the local time minus UTC.
+	///
+	/// # Examples
+	/// ```
+	/// use chrono::Duration;
+	/// use whatwg_datetime::parse_timezone_offset;
+	///
+	/// let offset = parse_timezone_offset("-07:00").unwrap();
+	/// assert_eq!(Duration::from(offset), Duration::minutes(-420));
+	/// ```
+	fn from(value: TimeZoneOffset) -> Self {
+		Duration::minutes(i64::from(value.total_minutes()))
+	}
+}
+
+/// Iterates over every valid time-zone offset, from `-23:59` to `+23:59`,
+/// in ascending order at minute granularity
+///
+/// # Examples
+/// ```
+/// use whatwg_datetime::{all_offsets, parse_timezone_offset};
+///
+/// let mut offsets = all_offsets();
+/// assert_eq!(offsets.next(), parse_timezone_offset("-23:59"));
+/// assert_eq!(offsets.next(), parse_timezone_offset("-23:58"));
+/// assert_eq!(all_offsets().count(), 2879);
+/// ```
+pub fn all_offsets() -> impl Iterator<Item = TimeZoneOffset> {
+	(-MAX_OFFSET_MINUTES..=MAX_OFFSET_MINUTES).map(offset_from_total_minutes)
+}
+
+/// Iterates over every valid time-zone offset which is a whole number of
+/// quarter hours (`:00`, `:15`, `:30`, or `:45`), from `-23:45` to `+23:45`,
+/// in ascending order
+///
+/// This is useful for populating a time-zone picker, since every time-zone
+/// offset in use is a multiple of 15 minutes.
+///
+/// # Examples
+/// ```
+/// use whatwg_datetime::{parse_timezone_offset, quarter_hour_offsets};
+///
+/// let mut offsets = quarter_hour_offsets();
+/// assert_eq!(offsets.next(), parse_timezone_offset("-23:45"));
+/// assert_eq!(offsets.next(), parse_timezone_offset("-23:30"));
+/// assert_eq!(quarter_hour_offsets().count(), 191);
+/// ```
+pub fn quarter_hour_offsets() -> impl Iterator<Item = TimeZoneOffset> {
+	all_offsets().filter(|offset| offset.minute % 15 == 0)
+}
+
+const MAX_OFFSET_MINUTES: i32 = 23 * 60 + 59;
+
+// The minutes have the same sign as the hours, like a parsed offset
+#[inline]
+fn offset_from_total_minutes(total_minutes: i32) -> TimeZoneOffset {
+	TimeZoneOffset::new(total_minutes / 60, total_minutes % 60)
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -119,6 +369,63 @@ pub fn parse_timezone_offset(s: &str) -> Option<TimeZoneOffset> {
 	parse_format(s, parse_timezone_offset_component)
 }
 
+/// Parse a string like [`parse_timezone_offset`], after stripping leading and trailing ASCII whitespace
+#[inline]
+pub fn parse_timezone_offset_trimmed(s: &str) -> Option<TimeZoneOffset> {
+	parse_timezone_offset(trim_ascii_whitespace(s))
+}
+
+/// Parse a time-zone offset, also accepting a number of seconds
+///
+/// This behaves the same as [`parse_timezone_offset`], except that an offset
+/// in the form `±HH:MM` may be followed by a colon and two digits for the
+/// seconds, e.g `+00:19:32`. This is used by historical local mean time
+/// offsets in the tz database, and is not a valid time-zone offset string
+/// per the WHATWG HTML Standard.
+///
+/// The seconds are returned separately from the offset, and are negative
+/// if the offset is negative, like the minutes of a [`TimeZoneOffset`].
+///
+/// # Examples
+/// ```
+/// use whatwg_datetime::{parse_timezone_offset, parse_timezone_offset_with_seconds, TimeZoneOffset};
+///
+/// assert_eq!(
+///     parse_timezone_offset_with_seconds("+00:19:32"),
+///     Some((TimeZoneOffset::new_opt(0, 19).unwrap(), 32))
+/// );
+/// assert_eq!(
+///     parse_timezone_offset_with_seconds("-07:00"),
+///     Some((TimeZoneOffset::new_opt(-7, 0).unwrap(), 0))
+/// );
+/// assert_eq!(parse_timezone_offset("+00:19:32"), None);
+/// ```
+pub fn parse_timezone_offset_with_seconds(s: &str) -> Option<(TimeZoneOffset, i32)> {
+	parse_format(s, |s, position| {
+		let start = *position;
+		let offset = parse_timezone_offset_component(s, position)?;
+
+		// Only the `±HH:MM` form may have seconds, so `Z` and `±HHMM` are left alone
+		if ascii_char_at(s, *position) != Some(Token::COLON)
+			|| ascii_char_at(s, start + 3) != Some(Token::COLON)
+		{
+			return Some((offset, 0));
+		}
+		*position += 1;
+
+		let (seconds, _) = collect_integer(s, position, 2, 2, true)?;
+		if !is_valid_min_or_sec(&seconds) {
+			return None;
+		}
+
+		let seconds = seconds as i32;
+		match ascii_char_at(s, start) {
+			Some(Token::MINUS) => Some((offset, -seconds)),
+			_ => Some((offset, seconds)),
+		}
+	})
+}
+
 /// Low-level function for parsing an individual timezone offset component
 /// at a given position
 ///
@@ -141,15 +448,31 @@ pub fn parse_timezone_offset(s: &str) -> Option<TimeZoneOffset> {
 ///
 /// [whatwg-html-tzoffset]: https://html.spec.whatwg.org/multipage/common-microsyntaxes.html#time-zones
 /// [whatwg-html-parse]: https://html.spec.whatwg.org/multipage/common-microsyntaxes.html#parse-a-time-zone-offset-component
+#[inline]
 pub fn parse_timezone_offset_component(s: &str, position: &mut usize) -> Option<TimeZoneOffset> {
-	let char_at = s.chars().nth(*position);
+	parse_timezone_offset_component_impl(s, position, false)
+}
+
+// If `lenient` is true, a lowercase `z` is also accepted as the UTC designator
+pub(crate) fn parse_timezone_offset_component_impl(
+	s: &str,
+	position: &mut usize,
+	lenient: bool,
+) -> Option<TimeZoneOffset> {
+	let char_at = ascii_char_at(s, *position);
 
 	let mut minutes = 0i32;
 	let mut hours = 0i32;
+	let mut was_z = false;
 
 	match char_at {
 		Some(Token::Z) => {
 			*position += 1;
+			was_z = true;
+		}
+		Some(c) if lenient && c == Token::Z.to_ascii_lowercase() => {
+			*position += 1;
+			was_z = true;
 		}
 		Some(Token::PLUS) | Some(Token::MINUS) => {
 			let sign = TimeZoneSign::try_from(char_at.unwrap()).ok().unwrap();
@@ -159,9 +482,7 @@ pub fn parse_timezone_offset_component(s: &str, position: &mut usize) -> Option<
 			let collected_len = collected.len();
 			if collected_len == 2 {
 				hours = collected.parse::<i32>().unwrap();
-				if *position > s.len()
-					|| s.chars().nth(*position) != Some(Token::COLON)
-				{
+				if ascii_char_at(s, *position) != Some(Token::COLON) {
 					return None;
 				} else {
 					*position += 1;
@@ -197,18 +518,214 @@ pub fn parse_timezone_offset_component(s: &str, position: &mut usize) -> Option<
 		_ => (),
 	}
 
-	Some(TimeZoneOffset::new(hours, minutes))
+	Some(TimeZoneOffset {
+		was_z,
+		..TimeZoneOffset::new(hours, minutes)
+	})
+}
+
+/// Serialize a time-zone offset into a valid time-zone offset string
+///
+/// This uses `Z` for an offset of zero, and otherwise a sign, followed by
+/// zero-padded 2-digit hours and minutes separated by a colon, e.g `-07:00`.
+/// This is the [best representation][whatwg-html-best] of the offset
+/// per [WHATWG HTML Standard § 2.3.5.6 Time zones][whatwg-html-tzoffset].
+///
+/// # Examples
+/// ```
+/// use whatwg_datetime::{parse_timezone_offset, serialize_timezone_offset};
+///
+/// let offset = parse_timezone_offset("-0700").unwrap();
+/// assert_eq!(serialize_timezone_offset(&offset), "-07:00");
+///
+/// let offset = parse_timezone_offset("+00:00").unwrap();
+/// assert_eq!(serialize_timezone_offset(&offset), "Z");
+/// ```
+///
+/// [whatwg-html-tzoffset]: https://html.spec.whatwg.org/multipage/common-microsyntaxes.html#time-zones
+/// [whatwg-html-best]: https://html.spec.whatwg.org/multipage/common-microsyntaxes.html#concept-datetime-best-representation
+pub fn serialize_timezone_offset(tz: &TimeZoneOffset) -> String {
+	format_timezone_offset(tz, Some(Token::COLON), true)
+}
+
+// If `zero_as_z` is true, an offset of zero is serialized as `Z`
+fn format_timezone_offset(tz: &TimeZoneOffset, separator: Option<char>, zero_as_z: bool) -> String {
+	let total_minutes = tz.total_minutes();
+	if zero_as_z && total_minutes == 0 {
+		return String::from(Token::Z);
+	}
+
+	let sign = if total_minutes < 0 {
+		Token::MINUS
+	} else {
+		Token::PLUS
+	};
+	let total_minutes = total_minutes.abs();
+
+	let mut s = format!("{}{:02}", sign, total_minutes / 60);
+	if let Some(separator) = separator {
+		s.push(separator);
+	}
+	s.push_str(&format!("{:02}", total_minutes % 60));
+	s
 }
 
 #[cfg(test)]
 mod tests {
 	#[rustfmt::skip]
 	use super::{
+		all_offsets,
 		parse_timezone_offset,
 		parse_timezone_offset_component,
+		parse_timezone_offset_component_impl,
+		parse_timezone_offset_with_seconds,
+		quarter_hour_offsets,
+		serialize_timezone_offset,
 		TimeZoneOffset,
 		TimeZoneSign,
 	};
+	use chrono::{Duration, FixedOffset};
+
+	#[test]
+	fn test_timezone_offset_is_whole_hour() {
+		assert!(parse_timezone_offset("+05:00").unwrap().is_whole_hour());
+		assert!(parse_timezone_offset("Z").unwrap().is_whole_hour());
+		assert!(!parse_timezone_offset("+05:30").unwrap().is_whole_hour());
+		assert!(!parse_timezone_offset("+05:45").unwrap().is_whole_hour());
+	}
+
+	#[test]
+	fn test_timezone_offset_is_half_hour() {
+		assert!(!parse_timezone_offset("+05:00").unwrap().is_half_hour());
+		assert!(parse_timezone_offset("+05:30").unwrap().is_half_hour());
+		assert!(parse_timezone_offset("-03:30").unwrap().is_half_hour());
+		assert!(!parse_timezone_offset("+05:45").unwrap().is_half_hour());
+	}
+
+	#[test]
+	fn test_timezone_offset_is_quarter_hour() {
+		assert!(!parse_timezone_offset("+05:00").unwrap().is_quarter_hour());
+		assert!(!parse_timezone_offset("+05:30").unwrap().is_quarter_hour());
+		assert!(parse_timezone_offset("+05:45").unwrap().is_quarter_hour());
+		assert!(parse_timezone_offset("+12:45").unwrap().is_quarter_hour());
+		assert!(!parse_timezone_offset("+05:20").unwrap().is_quarter_hour());
+	}
+
+	#[test]
+	fn test_timezone_offset_to_string_compact() {
+		assert_eq!(TimeZoneOffset::new(0, 0).to_string_compact(), "Z");
+		assert_eq!(TimeZoneOffset::new(5, 30).to_string_compact(), "+0530");
+		assert_eq!(TimeZoneOffset::new(-7, -30).to_string_compact(), "-0730");
+	}
+
+	#[test]
+	fn test_timezone_offset_round_trip_both_widths() {
+		for s in ["+05:30", "-07:00", "+23:59", "-00:01"] {
+			let offset = parse_timezone_offset(s).unwrap();
+			let compact = offset.to_string_compact();
+
+			assert_eq!(serialize_timezone_offset(&offset), s);
+			assert_eq!(compact, s.replace(':', ""));
+			assert_eq!(parse_timezone_offset(&compact), Some(offset));
+		}
+	}
+
+	#[test]
+	fn test_parse_timezone_offset_component_lenient_lowercase_z() {
+		let mut position = 0usize;
+		let parsed = parse_timezone_offset_component_impl("z", &mut position, true);
+
+		assert_eq!(parsed, Some(TimeZoneOffset::new(0, 0)));
+		assert_eq!(position, 1);
+	}
+
+	#[test]
+	fn test_parse_timezone_offset_fails_lowercase_z() {
+		assert_eq!(parse_timezone_offset("z"), None);
+	}
+
+	#[test]
+	fn test_parse_timezone_offset_with_seconds() {
+		assert_eq!(
+			parse_timezone_offset_with_seconds("+00:19:32"),
+			Some((TimeZoneOffset::new(0, 19), 32))
+		);
+		assert_eq!(
+			parse_timezone_offset_with_seconds("-00:01:15"),
+			Some((TimeZoneOffset::new(0, -1), -15))
+		);
+		assert_eq!(
+			parse_timezone_offset_with_seconds("-00:00:30"),
+			Some((TimeZoneOffset::new(0, 0), -30))
+		);
+	}
+
+	#[test]
+	fn test_parse_timezone_offset_with_seconds_without_seconds() {
+		assert_eq!(
+			parse_timezone_offset_with_seconds("+05:30"),
+			Some((TimeZoneOffset::new(5, 30), 0))
+		);
+		assert_eq!(
+			parse_timezone_offset_with_seconds("-0700"),
+			Some((TimeZoneOffset::new(-7, 0), 0))
+		);
+		assert_eq!(
+			parse_timezone_offset_with_seconds("Z"),
+			Some((TimeZoneOffset::new(0, 0), 0))
+		);
+	}
+
+	#[test]
+	fn test_parse_timezone_offset_with_seconds_fails() {
+		assert_eq!(parse_timezone_offset_with_seconds("+00:19:60"), None);
+		assert_eq!(parse_timezone_offset_with_seconds("+00:19:3"), None);
+		assert_eq!(parse_timezone_offset_with_seconds("+00:19:"), None);
+		assert_eq!(parse_timezone_offset_with_seconds("+0019:32"), None);
+		assert_eq!(parse_timezone_offset_with_seconds("Z:32"), None);
+		assert_eq!(parse_timezone_offset("+00:19:32"), None);
+	}
+
+	#[test]
+	fn test_timezone_offset_into_duration() {
+		assert_eq!(
+			Duration::from(parse_timezone_offset("+05:30").unwrap()),
+			Duration::minutes(330)
+		);
+		assert_eq!(
+			Duration::from(parse_timezone_offset("-07:00").unwrap()),
+			Duration::minutes(-420)
+		);
+		assert_eq!(
+			Duration::from(parse_timezone_offset("-03:30").unwrap()),
+			Duration::minutes(-210)
+		);
+		assert_eq!(
+			Duration::from(parse_timezone_offset("Z").unwrap()),
+			Duration::zero()
+		);
+	}
+
+	#[test]
+	fn test_timezone_offset_tryfrom_fixed_offset() {
+		let fixed_offset = FixedOffset::east_opt(5 * 3600 + 30 * 60).unwrap();
+		let parsed = TimeZoneOffset::try_from(fixed_offset);
+		assert_eq!(parsed, Ok(TimeZoneOffset::new(5, 30)));
+	}
+
+	#[test]
+	fn test_timezone_offset_tryfrom_fixed_offset_negative() {
+		let fixed_offset = FixedOffset::west_opt(7 * 3600 + 30 * 60).unwrap();
+		let parsed = TimeZoneOffset::try_from(fixed_offset);
+		assert_eq!(parsed.ok(), parse_timezone_offset("-07:30"));
+	}
+
+	#[test]
+	fn test_timezone_offset_tryfrom_fixed_offset_fails_seconds() {
+		let fixed_offset = FixedOffset::east_opt(30).unwrap();
+		let parsed = TimeZoneOffset::try_from(fixed_offset);
+		assert_eq!(parsed, Err(()));
+	}
 
 	#[test]
 	pub fn test_parse_timezone_sign_tryfrom_char_positive() {
@@ -228,6 +745,22 @@ mod tests {
 		assert_eq!(parsed, Err(()));
 	}
 
+	#[test]
+	fn test_timezone_offset_difference() {
+		let ist = TimeZoneOffset::new(5, 30);
+		let mst = TimeZoneOffset::new(-7, 0);
+		assert_eq!(ist.difference(&mst), 750);
+		assert_eq!(mst.difference(&ist), -750);
+	}
+
+	#[test]
+	fn test_timezone_offset_difference_negative_minutes() {
+		let nst = parse_timezone_offset("-03:30").unwrap();
+		let utc = parse_timezone_offset("Z").unwrap();
+		assert_eq!(nst.difference(&utc), -210);
+		assert_eq!(nst.difference(&nst), 0);
+	}
+
 	#[test]
 	pub fn test_parse_timezone_offset() {
 		let parsed = parse_timezone_offset("+01:00");
@@ -327,4 +860,109 @@ mod tests {
 
 		assert_eq!(parsed, None);
 	}
+
+	#[test]
+	fn test_serialize_timezone_offset() {
+		assert_eq!(
+			serialize_timezone_offset(&TimeZoneOffset::new(5, 30)),
+			"+05:30"
+		);
+		assert_eq!(
+			serialize_timezone_offset(&TimeZoneOffset::new(-7, 0)),
+			"-07:00"
+		);
+		assert_eq!(
+			serialize_timezone_offset(&TimeZoneOffset::new(0, -30)),
+			"-00:30"
+		);
+		assert_eq!(serialize_timezone_offset(&TimeZoneOffset::new(0, 0)), "Z");
+	}
+
+	#[test]
+	fn test_serialize_timezone_offset_roundtrip() {
+		for s in ["Z", "+05:30", "-07:00", "-00:30", "+23:59", "-23:59"] {
+			let parsed = parse_timezone_offset(s).unwrap();
+			assert_eq!(serialize_timezone_offset(&parsed), s);
+			assert_eq!(
+				parse_timezone_offset(&serialize_timezone_offset(&parsed)),
+				Some(parsed)
+			);
+		}
+	}
+
+	#[test]
+	fn test_parse_timezone_offset_was_z() {
+		let z = parse_timezone_offset("Z").unwrap();
+		let zero = parse_timezone_offset("+00:00").unwrap();
+
+		assert_eq!(z, zero);
+		assert!(z.was_z());
+		assert!(!zero.was_z());
+		assert!(!parse_timezone_offset("-0000").unwrap().was_z());
+		assert!(!parse_timezone_offset("+05:30").unwrap().was_z());
+	}
+
+	#[test]
+	fn test_parse_timezone_offset_lenient_was_z() {
+		let mut position = 0usize;
+		let parsed =
+			parse_timezone_offset_component_impl("z", &mut position, true).unwrap();
+		assert!(parsed.was_z());
+	}
+
+	#[test]
+	fn test_timezone_offset_to_string_original() {
+		#[rustfmt::skip]
+		let cases = [
+			("Z", "Z"),
+			("+00:00", "+00:00"),
+			("-00:00", "+00:00"),
+			("+0000", "+00:00"),
+			("-07:00", "-07:00"),
+			("+0530", "+05:30"),
+		];
+
+		for (s, expected) in cases {
+			assert_eq!(
+				parse_timezone_offset(s).unwrap().to_string_original(),
+				expected
+			);
+		}
+		assert_eq!(TimeZoneOffset::new(0, 0).to_string_original(), "+00:00");
+	}
+
+	#[test]
+	fn test_all_offsets() {
+		let offsets: Vec<TimeZoneOffset> = all_offsets().collect();
+		assert_eq!(offsets.len(), 2 * (23 * 60 + 59) + 1);
+		assert_eq!(offsets.first(), Some(&TimeZoneOffset::new(-23, -59)));
+		assert_eq!(offsets.last(), Some(&TimeZoneOffset::new(23, 59)));
+		assert!(offsets.contains(&parse_timezone_offset("Z").unwrap()));
+		assert!(offsets
+			.windows(2)
+			.all(|w| w[1].total_minutes() - w[0].total_minutes() == 1));
+	}
+
+	#[test]
+	fn test_all_offsets_round_trip() {
+		for offset in all_offsets() {
+			let serialized = serialize_timezone_offset(&offset);
+			assert_eq!(parse_timezone_offset(&serialized), Some(offset));
+		}
+	}
+
+	#[test]
+	fn test_quarter_hour_offsets() {
+		let offsets: Vec<TimeZoneOffset> = quarter_hour_offsets().collect();
+		assert_eq!(offsets.len(), 2 * 95 + 1);
+		assert_eq!(offsets.first(), Some(&TimeZoneOffset::new(-23, -45)));
+		assert_eq!(offsets.last(), Some(&TimeZoneOffset::new(23, 45)));
+		assert!(offsets.contains(&TimeZoneOffset::new(0, 0)));
+		assert!(offsets.contains(&parse_timezone_offset("+05:45").unwrap()));
+		assert!(offsets.contains(&parse_timezone_offset("-09:30").unwrap()));
+		assert!(offsets
+			.iter()
+			.all(|offset| offset.is_whole_hour()
+				|| offset.is_half_hour() || offset.is_quarter_hour()));
+	}
 }
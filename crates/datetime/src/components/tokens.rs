@@ -1,17 +1,51 @@
-pub(crate) struct Token;
+/// Delimiters and designators used by the datetime microsyntaxes
+///
+/// These are the exact code points the parsers in this crate expect
+/// between and around each component, as defined in
+/// [WHATWG HTML Standard § 2.3.5 Dates and times][whatwg-html-datetime].
+///
+/// # Examples
+/// ```
+/// use whatwg_datetime::tokens::Token;
+///
+/// let s = "2011-11";
+/// assert_eq!(s.chars().nth(4), Some(Token::HYPHEN));
+/// ```
+///
+/// [whatwg-html-datetime]: https://html.spec.whatwg.org/multipage/common-microsyntaxes.html#dates-and-times
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Token;
 
 impl Token {
-	// pub const ABBR_DAY: char = 'D';
-	// pub const ABBR_HOUR: char = 'H';
-	// pub const ABBR_MIN: char = 'M';
-	// pub const ABBR_SEC: char = 'S';
+	/// U+0050 LATIN CAPITAL LETTER P, which begins a duration in the ISO 8601 form
+	pub const ABBR_PERIOD: char = 'P';
+	/// U+0059 LATIN CAPITAL LETTER Y, which designates years in a duration
+	pub const ABBR_YEAR: char = 'Y';
+	/// U+0044 LATIN CAPITAL LETTER D, which designates days in a duration
+	pub const ABBR_DAY: char = 'D';
+	/// U+0048 LATIN CAPITAL LETTER H, which designates hours in a duration
+	pub const ABBR_HOUR: char = 'H';
+	/// U+004D LATIN CAPITAL LETTER M, which designates months or minutes in a duration
+	pub const ABBR_MIN: char = 'M';
+	/// U+0053 LATIN CAPITAL LETTER S, which designates seconds in a duration
+	pub const ABBR_SEC: char = 'S';
+	/// U+0057 LATIN CAPITAL LETTER W, which designates the week number in a week string,
+	/// or weeks in a duration
 	pub const ABBR_WEEK: char = 'W';
+	/// U+002D HYPHEN-MINUS, which separates the components of a date
 	pub const HYPHEN: char = '-';
+	/// U+003A COLON, which separates the components of a time
 	pub const COLON: char = ':';
+	/// U+0054 LATIN CAPITAL LETTER T, which may separate a date and a time
 	pub const T: char = 'T';
+	/// U+005A LATIN CAPITAL LETTER Z, which designates a UTC time-zone offset
 	pub const Z: char = 'Z';
+	/// U+002B PLUS SIGN, which designates a positive time-zone offset
 	pub const PLUS: char = '+';
+	/// U+002D HYPHEN-MINUS, which designates a negative time-zone offset
 	pub const MINUS: char = '-';
+	/// U+002E FULL STOP, which separates seconds from a fraction of a second
 	pub const DOT: char = '.';
+	/// U+0020 SPACE, which may separate a date and a time
 	pub const SPACE: char = ' ';
 }
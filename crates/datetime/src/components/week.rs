@@ -1,5 +1,7 @@
 use crate::tokens::Token;
-use crate::utils::{collect_ascii_digits, week_number_of_year};
+use crate::utils::{ascii_char_at, collect_ascii_digits_str, week_number_of_year};
+use chrono::{Datelike, Duration, NaiveDate, Weekday};
+use whatwg_infra::trim_ascii_whitespace;
 
 /// A week date consisting of a year and a week number.
 ///
@@ -9,7 +11,7 @@ use crate::utils::{collect_ascii_digits, week_number_of_year};
 ///
 /// assert_eq!(parse_week("2011-W47"), YearWeek::new_opt(2011, 47));
 /// ```
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Clone, Copy, PartialEq, Eq)]
 pub struct YearWeek {
 	pub(crate) year: i32,
 	pub(crate) week: u32,
@@ -80,6 +82,103 @@ impl YearWeek {
 	pub const fn week(&self) -> u32 {
 		self.week
 	}
+
+	/// The first and last dates of the week, which are the Monday and
+	/// the Sunday of the week, respectively.
+	///
+	/// The first week of a year may start in the previous calendar year,
+	/// and the last week of a year may end in the next calendar year.
+	/// This returns [`None`] if the dates are outside of the range
+	/// supported by [`NaiveDate`].
+	///
+	/// # Examples
+	/// ```
+	/// use chrono::NaiveDate;
+	/// use whatwg_datetime::YearWeek;
+	///
+	/// let year_week = YearWeek::new_opt(2004, 53).unwrap();
+	/// assert_eq!(
+	///     year_week.date_range(),
+	///     Some((
+	///         NaiveDate::from_ymd_opt(2004, 12, 27).unwrap(),
+	///         NaiveDate::from_ymd_opt(2005, 1, 2).unwrap(),
+	///     ))
+	/// );
+	/// ```
+	pub fn date_range(&self) -> Option<(NaiveDate, NaiveDate)> {
+		let monday = NaiveDate::from_isoywd_opt(self.year, self.week, Weekday::Mon)?;
+		let sunday = NaiveDate::from_isoywd_opt(self.year, self.week, Weekday::Sun)?;
+		Some((monday, sunday))
+	}
+
+	/// The number of weeks since the first week of year 1, which is `0`
+	///
+	/// Since a week-year has either 52 or 53 weeks, this counts the days
+	/// between the Mondays of the two weeks, rather than multiplying the year.
+	/// See [`weeks_between`] for the difference between two weeks.
+	///
+	/// # Examples
+	/// ```
+	/// use whatwg_datetime::YearWeek;
+	///
+	/// assert_eq!(YearWeek::new_opt(1, 1).unwrap().ordinal(), 0);
+	/// assert_eq!(YearWeek::new_opt(1, 2).unwrap().ordinal(), 1);
+	///
+	/// let last = YearWeek::new_opt(2004, 53).unwrap();
+	/// let first = YearWeek::new_opt(2005, 1).unwrap();
+	/// assert_eq!(first.ordinal() - last.ordinal(), 1);
+	/// ```
+	pub fn ordinal(&self) -> i64 {
+		// A `YearWeek` is always within the range supported by `NaiveDate`
+		let anchor = iso_week_anchor(self.year).expect("year is in range");
+		let monday = i64::from(anchor.num_days_from_ce()) + 7 * i64::from(self.week - 1);
+
+		// The first week-year starts on Monday, January 1st of year 1
+		(monday - 1) / 7
+	}
+}
+
+/// The signed number of weeks from one week to another
+///
+/// This is positive if `b` is after `a`, and negative if `b` is before `a`.
+///
+/// # Examples
+/// ```
+/// use whatwg_datetime::{weeks_between, YearWeek};
+///
+/// let a = YearWeek::new_opt(2004, 52).unwrap();
+/// let b = YearWeek::new_opt(2005, 2).unwrap();
+/// assert_eq!(weeks_between(&a, &b), 3); // 2004 has 53 weeks
+/// assert_eq!(weeks_between(&b, &a), -3);
+/// ```
+pub fn weeks_between(a: &YearWeek, b: &YearWeek) -> i64 {
+	b.ordinal() - a.ordinal()
+}
+
+/// The Monday of the first week of a week-year
+///
+/// Per ISO 8601, which the [week-number-of-the-year][whatwg-html-weeks]
+/// definition follows, week 1 is the week containing January 4th. So the
+/// week-year may start as early as December 29th of the previous calendar
+/// year, or as late as January 4th.
+///
+/// This returns [`None`] if the date is outside of the range supported
+/// by [`NaiveDate`].
+///
+/// # Examples
+/// ```
+/// use chrono::NaiveDate;
+/// use whatwg_datetime::iso_week_anchor;
+///
+/// assert_eq!(iso_week_anchor(2004), NaiveDate::from_ymd_opt(2003, 12, 29));
+/// assert_eq!(iso_week_anchor(2011), NaiveDate::from_ymd_opt(2011, 1, 3));
+/// ```
+///
+/// [whatwg-html-weeks]: https://html.spec.whatwg.org/multipage/common-microsyntaxes.html#weeks
+pub fn iso_week_anchor(year: i32) -> Option<NaiveDate> {
+	let january_4th = NaiveDate::from_ymd_opt(year, 1, 4)?;
+	let days_since_monday = january_4th.weekday().num_days_from_monday();
+	january_4th.checked_sub_signed(Duration::days(i64::from(days_since_monday)))
 }
 
 /// Parse a week-year number and a week-number
@@ -98,55 +197,192 @@ impl YearWeek {
 ///
 /// [whatwg-html-weeks]: https://html.spec.whatwg.org/multipage/common-microsyntaxes.html#weeks
 /// [whatwg-html-parse]: https://html.spec.whatwg.org/multipage/common-microsyntaxes.html#parse-a-week-string
+#[inline]
 pub fn parse_week(input: &str) -> Option<YearWeek> {
+	parse_week_impl(input, false)
+}
+
+/// Parse a string like [`parse_week`], after stripping leading and trailing ASCII whitespace
+#[inline]
+pub fn parse_week_trimmed(s: &str) -> Option<YearWeek> {
+	parse_week(trim_ascii_whitespace(s))
+}
+
+/// Parse a week-year number and a week-number, accepting either an uppercase
+/// or lowercase week designator
+///
+/// This behaves the same as [`parse_week`], except that the week designator
+/// may also be a U+0077 LATIN SMALL LETTER W, which some producers emit.
+/// Note that this is not a valid week string per the WHATWG HTML Standard.
+///
+/// # Examples
+/// ```
+/// use whatwg_datetime::{parse_week, parse_week_lenient, YearWeek};
+///
+/// assert_eq!(parse_week_lenient("2011-w47"), YearWeek::new_opt(2011, 47));
+/// assert_eq!(parse_week_lenient("2011-W47"), YearWeek::new_opt(2011, 47));
+/// assert_eq!(parse_week("2011-w47"), None);
+/// ```
+#[inline]
+pub fn parse_week_lenient(input: &str) -> Option<YearWeek> {
+	parse_week_impl(input, true)
+}
+
+fn parse_week_impl(input: &str, lenient: bool) -> Option<YearWeek> {
 	// Step 1, 2
 	let mut position = 0usize;
 
 	// Step 3, 4
-	let year_string = collect_ascii_digits(input, &mut position);
-	let year = year_string.parse::<i32>().unwrap();
+	let year_string = collect_ascii_digits_str(input, &mut position);
+	if year_string.len() < 4 {
+		return None;
+	}
+
+	let year = year_string.parse::<i32>().ok()?;
 	if year <= 0 {
 		return None;
 	}
 
 	// Step 5
-	if position > input.len() || input.chars().nth(position) != Some(Token::HYPHEN) {
+	if ascii_char_at(input, position) != Some(Token::HYPHEN) {
 		return None;
 	} else {
 		position += 1;
 	}
 
 	// Step 6
-	if position > input.len() || input.chars().nth(position) != Some(Token::ABBR_WEEK) {
-		return None;
-	} else {
-		position += 1;
+	match ascii_char_at(input, position) {
+		Some(Token::ABBR_WEEK) => position += 1,
+		Some(c) if lenient && c == Token::ABBR_WEEK.to_ascii_lowercase() => position += 1,
+		_ => return None,
 	}
 
 	// Step 7
-	let parsed_week = collect_ascii_digits(input, &mut position);
+	let parsed_week = collect_ascii_digits_str(input, &mut position);
 	if parsed_week.len() != 2 {
 		return None;
 	}
 
-	let week = parsed_week.parse::<u32>().unwrap();
+	let week = parsed_week.parse::<u32>().ok()?;
 	let max_weeks = week_number_of_year(year)?;
 	if week < 1 || week > max_weeks {
 		return None;
 	}
 
+	// Step 8
+	if position < input.len() {
+		return None;
+	}
+
 	Some(YearWeek::new(year, week))
 }
 
 #[cfg(test)]
 mod tests {
-	use super::{parse_week, YearWeek};
+	use super::{iso_week_anchor, parse_week, parse_week_lenient, weeks_between, YearWeek};
+	use chrono::{Datelike, NaiveDate, Weekday};
+
+	#[test]
+	fn test_iso_week_anchor() {
+		#[rustfmt::skip]
+		let cases = [
+			(2004, (2003, 12, 29)), // January 4th is a Sunday
+			(2009, (2008, 12, 29)), // January 4th is a Sunday
+			(2010, (2010, 1, 4)),   // January 4th is a Monday
+			(2011, (2011, 1, 3)),
+			(2015, (2014, 12, 29)),
+			(2021, (2021, 1, 4)),
+		];
+
+		for (year, (y, m, d)) in cases {
+			assert_eq!(iso_week_anchor(year), NaiveDate::from_ymd_opt(y, m, d));
+		}
+	}
+
+	#[test]
+	fn test_iso_week_anchor_is_monday_of_week_1() {
+		for year in 1970..2070 {
+			let anchor = iso_week_anchor(year).unwrap();
+			assert_eq!(anchor.weekday(), Weekday::Mon);
+			assert_eq!(
+				anchor,
+				NaiveDate::from_isoywd_opt(year, 1, Weekday::Mon).unwrap()
+			);
+			assert_eq!(
+				Some(anchor),
+				YearWeek::new_opt(year, 1)
+					.unwrap()
+					.date_range()
+					.map(|(monday, _)| monday)
+			);
+		}
+	}
+
+	#[test]
+	fn test_iso_week_anchor_fails_out_of_range() {
+		assert_eq!(iso_week_anchor(i32::MAX), None);
+	}
+
+	#[test]
+	fn test_year_week_date_range_mid_year() {
+		assert_eq!(
+			YearWeek::new(2011, 47).date_range(),
+			Some((
+				NaiveDate::from_ymd_opt(2011, 11, 21).unwrap(),
+				NaiveDate::from_ymd_opt(2011, 11, 27).unwrap(),
+			))
+		);
+	}
+
+	#[test]
+	fn test_year_week_date_range_spills_into_next_year() {
+		assert_eq!(
+			YearWeek::new(2004, 53).date_range(),
+			Some((
+				NaiveDate::from_ymd_opt(2004, 12, 27).unwrap(),
+				NaiveDate::from_ymd_opt(2005, 1, 2).unwrap(),
+			))
+		);
+	}
+
+	#[test]
+	fn test_year_week_date_range_spills_into_previous_year() {
+		assert_eq!(
+			YearWeek::new(2009, 1).date_range(),
+			Some((
+				NaiveDate::from_ymd_opt(2008, 12, 29).unwrap(),
+				NaiveDate::from_ymd_opt(2009, 1, 4).unwrap(),
+			))
+		);
+	}
 
 	#[test]
 	fn test_parse_week() {
 		assert_eq!(parse_week("2004-W53"), Some(YearWeek::new(2004, 53)));
 	}
 
+	#[test]
+	fn test_parse_week_fails_empty_string() {
+		assert_eq!(parse_week(""), None);
+	}
+
+	#[test]
+	fn test_parse_week_fails_year_out_of_range() {
+		assert_eq!(parse_week("99999999999-W01"), None);
+		assert_eq!(parse_week("999999-W01"), None);
+	}
+
+	#[test]
+	fn test_parse_week_fails_year_lt_4_digits() {
+		assert_eq!(parse_week("201-W47"), None);
+	}
+
+	#[test]
+	fn test_parse_week_fails_trailing_characters() {
+		assert_eq!(parse_week("2011-W47T14:54"), None);
+		assert_eq!(parse_week("2011-W47 "), None);
+	}
+
 	#[test]
 	fn test_parse_week_fails_year_is_zero() {
 		assert_eq!(parse_week("0000-W01"), None);
@@ -179,4 +415,79 @@ mod tests {
 		assert_eq!(parse_week("2004-W54"), None);
 		assert_eq!(parse_week("1996-W53"), None);
 	}
+
+	#[test]
+	fn test_parse_week_fails_lowercase_week_abbr() {
+		assert_eq!(parse_week("2011-w47"), None);
+	}
+
+	#[test]
+	fn test_parse_week_lenient() {
+		assert_eq!(
+			parse_week_lenient("2011-w47"),
+			Some(YearWeek::new(2011, 47))
+		);
+		assert_eq!(
+			parse_week_lenient("2011-W47"),
+			Some(YearWeek::new(2011, 47))
+		);
+	}
+
+	#[test]
+	fn test_parse_week_lenient_fails_invalid_week_abbr() {
+		assert_eq!(parse_week_lenient("2011-x47"), None);
+		assert_eq!(parse_week_lenient("2011-w53"), None);
+	}
+
+	#[test]
+	fn test_parse_week_fails_year_out_of_chrono_range() {
+		assert_eq!(parse_week("300000-W01"), None);
+		assert_eq!(parse_week("2147483647-W01"), None);
+		assert_eq!(YearWeek::new_opt(300000, 1), None);
+	}
+
+	#[test]
+	fn test_year_week_ordinal() {
+		assert_eq!(YearWeek::new(1, 1).ordinal(), 0);
+		assert_eq!(YearWeek::new(1, 52).ordinal(), 51);
+		assert_eq!(YearWeek::new(2, 1).ordinal(), 52);
+	}
+
+	#[test]
+	fn test_year_week_ordinal_monotonic() {
+		for year in 2000..2030 {
+			let last = YearWeek::new_opt(
+				year,
+				crate::utils::week_number_of_year(year).unwrap(),
+			)
+			.unwrap();
+			let first = YearWeek::new_opt(year + 1, 1).unwrap();
+			assert_eq!(first.ordinal() - last.ordinal(), 1);
+		}
+	}
+
+	#[test]
+	fn test_weeks_between_year_boundary() {
+		// 2004 has 53 weeks, while 2005 has 52 weeks
+		let a = YearWeek::new(2004, 50);
+		let b = YearWeek::new(2005, 3);
+		assert_eq!(weeks_between(&a, &b), 6);
+		assert_eq!(weeks_between(&b, &a), -6);
+
+		let a = YearWeek::new(2005, 50);
+		let b = YearWeek::new(2006, 3);
+		assert_eq!(weeks_between(&a, &b), 5);
+
+		let a = YearWeek::new(2011, 47);
+		assert_eq!(weeks_between(&a, &a), 0);
+	}
+
+	#[test]
+	fn test_weeks_between_matches_dates() {
+		let a = YearWeek::new(1999, 10);
+		let b = YearWeek::new(2011, 47);
+		let (a_monday, _) = a.date_range().unwrap();
+		let (b_monday, _) = b.date_range().unwrap();
+		assert_eq!(weeks_between(&a, &b), (b_monday - a_monday).num_weeks());
+	}
 }
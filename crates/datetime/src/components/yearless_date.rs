@@ -1,7 +1,8 @@
 use crate::tokens::Token;
-use crate::utils::is_valid_month;
+use crate::utils::{ascii_char_at, collect_codepoints_at, is_valid_month, max_days_in_month_year};
 use crate::{collect_day_and_validate, collect_month_and_validate, parse_format};
-use whatwg_infra::collect_codepoints;
+use chrono::{Datelike, NaiveDate};
+use whatwg_infra::trim_ascii_whitespace;
 
 /// A yearless date, consisting of a gregorian month and a day
 /// within the month, without an associated year.
@@ -13,7 +14,10 @@ use whatwg_infra::collect_codepoints;
 ///
 /// assert_eq!(parse_yearless_date("11-18"), YearlessDate::new_opt(11, 18));
 /// ```
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+///
+/// Yearless dates are ordered by month, then by day, so February 29th is
+/// ordered after February 28th and before March 1st.
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
 pub struct YearlessDate {
 	pub(crate) month: u32,
 	pub(crate) day: u32,
@@ -44,10 +48,11 @@ impl YearlessDate {
 	/// assert!(YearlessDate::new_opt(4, 31).is_none()); // April only has 30 days
 	/// assert!(YearlessDate::new_opt(13, 1).is_none()); // There are only 12 months
 	/// assert!(YearlessDate::new_opt(12, 32).is_none()); // December only has 31 days
+	/// assert!(YearlessDate::new_opt(1, 0).is_none()); // Days start from 1
 	/// ```
 	#[rustfmt::skip]
 	pub fn new_opt(month: u32, day: u32) -> Option<Self> {
-		if !is_valid_month(&month) {
+		if !is_valid_month(&month) || day == 0 {
 			return None;
 		}
 
@@ -60,6 +65,24 @@ impl YearlessDate {
 		Some(Self::new(month, day))
 	}
 
+	/// Creates a new `YearlessDate` from the month and day of a date,
+	/// discarding its year.
+	///
+	/// Since a yearless date isn't tied to any year, February 29th of
+	/// a leap year converts to a yearless date of `02-29`.
+	///
+	/// # Examples
+	/// ```
+	/// use chrono::NaiveDate;
+	/// use whatwg_datetime::YearlessDate;
+	///
+	/// let date = NaiveDate::from_ymd_opt(2011, 11, 18).unwrap();
+	/// assert_eq!(YearlessDate::from_naive_date(date), YearlessDate::new_opt(11, 18));
+	/// ```
+	pub fn from_naive_date(d: NaiveDate) -> Option<Self> {
+		Self::new_opt(d.month(), d.day())
+	}
+
 	/// A month component. This is a number from 1 to 12, inclusive.
 	///
 	/// # Examples
@@ -88,6 +111,82 @@ impl YearlessDate {
 	pub const fn day(&self) -> u32 {
 		self.day
 	}
+
+	/// The next yearless date, wrapping from December 31st to January 1st.
+	///
+	/// Since there is no associated year, February is always treated as
+	/// having 29 days.
+	///
+	/// # Examples
+	/// ```
+	/// use whatwg_datetime::YearlessDate;
+	///
+	/// let date = YearlessDate::new_opt(2, 28).unwrap();
+	/// assert_eq!(Some(date.succ()), YearlessDate::new_opt(2, 29));
+	///
+	/// let date = YearlessDate::new_opt(12, 31).unwrap();
+	/// assert_eq!(Some(date.succ()), YearlessDate::new_opt(1, 1));
+	/// ```
+	pub fn succ(&self) -> Self {
+		if self.day < days_in_yearless_month(self.month) {
+			Self::new(self.month, self.day + 1)
+		} else if self.month < 12 {
+			Self::new(self.month + 1, 1)
+		} else {
+			Self::new(1, 1)
+		}
+	}
+
+	/// The previous yearless date, wrapping from January 1st to December 31st.
+	///
+	/// Since there is no associated year, February is always treated as
+	/// having 29 days.
+	///
+	/// # Examples
+	/// ```
+	/// use whatwg_datetime::YearlessDate;
+	///
+	/// let date = YearlessDate::new_opt(3, 1).unwrap();
+	/// assert_eq!(Some(date.pred()), YearlessDate::new_opt(2, 29));
+	///
+	/// let date = YearlessDate::new_opt(1, 1).unwrap();
+	/// assert_eq!(Some(date.pred()), YearlessDate::new_opt(12, 31));
+	/// ```
+	pub fn pred(&self) -> Self {
+		if self.day > 1 {
+			Self::new(self.month, self.day - 1)
+		} else if self.month > 1 {
+			Self::new(self.month - 1, days_in_yearless_month(self.month - 1))
+		} else {
+			Self::new(12, 31)
+		}
+	}
+
+	/// The day of the year, from 1 to 366, inclusive.
+	///
+	/// Since there is no associated year, this treats the year as a leap year,
+	/// so that February 29th is always valid. This is useful for ordering
+	/// recurring dates, such as birthdays.
+	///
+	/// # Examples
+	/// ```
+	/// use whatwg_datetime::YearlessDate;
+	///
+	/// assert_eq!(YearlessDate::new_opt(1, 1).unwrap().day_of_year_ignoring_leap(), 1);
+	/// assert_eq!(YearlessDate::new_opt(2, 29).unwrap().day_of_year_ignoring_leap(), 60);
+	/// assert_eq!(YearlessDate::new_opt(3, 1).unwrap().day_of_year_ignoring_leap(), 61);
+	/// assert_eq!(YearlessDate::new_opt(12, 31).unwrap().day_of_year_ignoring_leap(), 366);
+	/// ```
+	pub fn day_of_year_ignoring_leap(&self) -> u32 {
+		(1..self.month).map(days_in_yearless_month).sum::<u32>() + self.day
+	}
+}
+
+// February may have 29 days in a yearless date, so this
+// uses an arbitrary leap year to find the number of days
+#[inline]
+fn days_in_yearless_month(month: u32) -> u32 {
+	max_days_in_month_year(month, 4).unwrap()
 }
 
 /// Parses a string consisting of a gregorian month and a day
@@ -96,11 +195,17 @@ impl YearlessDate {
 /// This follows the rules for [parsing a yearless date string][whatwg-html-parse]
 /// per [WHATWG HTML Standard § 2.3.5.3 Yearless dates][whatwg-html-yearless].
 ///
+/// The month may be preceded by exactly two hyphens, as in the `--MM-DD`
+/// form of XML Schema's `gMonthDay`. Any other number of leading hyphens
+/// is rejected.
+///
 /// # Examples
 /// ```
 /// use whatwg_datetime::{parse_yearless_date, YearlessDate};
 ///
 /// assert_eq!(parse_yearless_date("11-18"), YearlessDate::new_opt(11, 18));
+/// assert_eq!(parse_yearless_date("--11-18"), YearlessDate::new_opt(11, 18));
+/// assert_eq!(parse_yearless_date("-11-18"), None);
 /// assert_eq!(parse_yearless_date("02-29"), YearlessDate::new_opt(2, 29));
 /// assert_eq!(parse_yearless_date("02-30"), None); // February never has 30 days
 /// assert_eq!(parse_yearless_date("04-31"), None); // April only has 30 days
@@ -114,6 +219,12 @@ pub fn parse_yearless_date(s: &str) -> Option<YearlessDate> {
 	parse_format(s, parse_yearless_date_component)
 }
 
+/// Parse a string like [`parse_yearless_date`], after stripping leading and trailing ASCII whitespace
+#[inline]
+pub fn parse_yearless_date_trimmed(s: &str) -> Option<YearlessDate> {
+	parse_yearless_date(trim_ascii_whitespace(s))
+}
+
 /// Low-level function for parsing an individual yearless date component
 /// at a given position
 ///
@@ -137,13 +248,13 @@ pub fn parse_yearless_date(s: &str) -> Option<YearlessDate> {
 /// [whatwg-html-yearless]: https://html.spec.whatwg.org/multipage/common-microsyntaxes.html#yearless-dates
 /// [whatwg-html-parse]: https://html.spec.whatwg.org/multipage/common-microsyntaxes.html#parse-a-yearless-date-component
 pub fn parse_yearless_date_component(s: &str, position: &mut usize) -> Option<YearlessDate> {
-	let collected = collect_codepoints(s, position, |c| c == Token::HYPHEN);
+	let collected = collect_codepoints_at(s, position, |c| c == Token::HYPHEN);
 	if !matches!(collected.len(), 0 | 2) {
 		return None;
 	}
 
 	let month = collect_month_and_validate(s, position)?;
-	if *position > s.len() || s.chars().nth(*position) != Some(Token::HYPHEN) {
+	if ascii_char_at(s, *position) != Some(Token::HYPHEN) {
 		return None;
 	} else {
 		*position += 1;
@@ -161,6 +272,51 @@ mod tests {
 		parse_yearless_date_component,
 		YearlessDate,
 	};
+	use chrono::NaiveDate;
+
+	#[test]
+	fn test_yearless_date_from_naive_date() {
+		let date = NaiveDate::from_ymd_opt(2011, 11, 18).unwrap();
+		assert_eq!(
+			YearlessDate::from_naive_date(date),
+			Some(YearlessDate::new(11, 18))
+		);
+	}
+
+	#[test]
+	fn test_yearless_date_from_naive_date_leap_day() {
+		let date = NaiveDate::from_ymd_opt(2020, 2, 29).unwrap();
+		assert_eq!(
+			YearlessDate::from_naive_date(date),
+			Some(YearlessDate::new(2, 29))
+		);
+	}
+
+	#[test]
+	fn test_yearless_date_succ() {
+		assert_eq!(YearlessDate::new(11, 18).succ(), YearlessDate::new(11, 19));
+		assert_eq!(YearlessDate::new(4, 30).succ(), YearlessDate::new(5, 1));
+		assert_eq!(YearlessDate::new(12, 31).succ(), YearlessDate::new(1, 1));
+	}
+
+	#[test]
+	fn test_yearless_date_succ_february() {
+		assert_eq!(YearlessDate::new(2, 28).succ(), YearlessDate::new(2, 29));
+		assert_eq!(YearlessDate::new(2, 29).succ(), YearlessDate::new(3, 1));
+	}
+
+	#[test]
+	fn test_yearless_date_pred() {
+		assert_eq!(YearlessDate::new(11, 18).pred(), YearlessDate::new(11, 17));
+		assert_eq!(YearlessDate::new(5, 1).pred(), YearlessDate::new(4, 30));
+		assert_eq!(YearlessDate::new(1, 1).pred(), YearlessDate::new(12, 31));
+	}
+
+	#[test]
+	fn test_yearless_date_pred_february() {
+		assert_eq!(YearlessDate::new(3, 1).pred(), YearlessDate::new(2, 29));
+		assert_eq!(YearlessDate::new(2, 29).pred(), YearlessDate::new(2, 28));
+	}
 
 	#[test]
 	fn test_parse_yearless_date() {
@@ -170,6 +326,24 @@ mod tests {
 		);
 	}
 
+	#[test]
+	fn test_parse_yearless_date_two_leading_hyphens() {
+		assert_eq!(
+			parse_yearless_date("--11-18"),
+			Some(YearlessDate::new(11, 18))
+		);
+	}
+
+	#[test]
+	fn test_parse_yearless_date_fails_one_leading_hyphen() {
+		assert_eq!(parse_yearless_date("-11-18"), None);
+	}
+
+	#[test]
+	fn test_parse_yearless_date_fails_three_leading_hyphens() {
+		assert_eq!(parse_yearless_date("---11-18"), None);
+	}
+
 	#[test]
 	fn test_parse_yearless_date_fails_empty_string() {
 		assert_eq!(parse_yearless_date(""), None);
@@ -221,6 +395,23 @@ mod tests {
 		assert_eq!(parsed, None);
 	}
 
+	#[test]
+	fn test_parse_yearless_date_component_multibyte_prefix() {
+		let mut position = 3usize;
+		let parsed = parse_yearless_date_component("\u{65E5}--11-18", &mut position);
+
+		assert_eq!(parsed, Some(YearlessDate::new(11, 18)));
+		assert_eq!(position, 10);
+	}
+
+	#[test]
+	fn test_parse_yearless_date_component_fails_not_char_boundary() {
+		let mut position = 1usize;
+		let parsed = parse_yearless_date_component("\u{65E5}11-18", &mut position);
+
+		assert_eq!(parsed, None);
+	}
+
 	#[test]
 	fn test_parse_yearless_date_only_one_separator() {
 		let mut position = 0usize;
@@ -228,4 +419,62 @@ mod tests {
 
 		assert_eq!(parsed, None);
 	}
+
+	#[test]
+	fn test_yearless_date_day_of_year_ignoring_leap() {
+		assert_eq!(YearlessDate::new(1, 1).day_of_year_ignoring_leap(), 1);
+		assert_eq!(YearlessDate::new(1, 31).day_of_year_ignoring_leap(), 31);
+		assert_eq!(YearlessDate::new(2, 28).day_of_year_ignoring_leap(), 59);
+		assert_eq!(YearlessDate::new(2, 29).day_of_year_ignoring_leap(), 60);
+		assert_eq!(YearlessDate::new(3, 1).day_of_year_ignoring_leap(), 61);
+		assert_eq!(YearlessDate::new(12, 31).day_of_year_ignoring_leap(), 366);
+	}
+
+	#[test]
+	fn test_yearless_date_new_opt_fails_day_zero() {
+		for month in 1..=12 {
+			assert_eq!(YearlessDate::new_opt(month, 0), None);
+		}
+	}
+
+	#[test]
+	fn test_yearless_date_day_of_year_matches_succ() {
+		let mut date = YearlessDate::new(1, 1);
+		for day_of_year in 1..=366 {
+			assert_eq!(date.day_of_year_ignoring_leap(), day_of_year);
+			date = date.succ();
+		}
+		assert_eq!(date, YearlessDate::new(1, 1));
+	}
+
+	#[test]
+	fn test_yearless_date_ordering() {
+		let mut dates = vec![
+			YearlessDate::new(3, 1),
+			YearlessDate::new(12, 31),
+			YearlessDate::new(2, 29),
+			YearlessDate::new(1, 1),
+			YearlessDate::new(2, 28),
+			YearlessDate::new(11, 18),
+			YearlessDate::new(2, 1),
+		];
+		dates.sort();
+
+		assert_eq!(
+			dates,
+			vec![
+				YearlessDate::new(1, 1),
+				YearlessDate::new(2, 1),
+				YearlessDate::new(2, 28),
+				YearlessDate::new(2, 29),
+				YearlessDate::new(3, 1),
+				YearlessDate::new(11, 18),
+				YearlessDate::new(12, 31),
+			]
+		);
+		assert!(dates
+			.windows(2)
+			.all(|w| w[0].day_of_year_ignoring_leap()
+				< w[1].day_of_year_ignoring_leap()));
+	}
 }
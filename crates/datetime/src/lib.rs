@@ -8,7 +8,7 @@
 //!
 //! ## Usage
 //!
-//! This library currently implements 8 of the 9 datetime formats defined by the WHATWG HTML Standard. The only format not implemented is the duration format, which is tracked in [issue #23](https://github.com/neoncitylights/whatwg-rust/issues/23).
+//! This library implements all 9 datetime formats defined by the WHATWG HTML Standard.
 //!
 //! ```rust
 //! use chrono::{NaiveDate, NaiveDateTime, NaiveTime, TimeZone, Utc};
@@ -24,11 +24,47 @@
 //!     ))
 //! );
 //! ```
+//!
+//! ## Positions
+//!
+//! The low-level component parsers (e.g [`parse_date_component`]) take a
+//! `position`, which is a byte index into the string, like when slicing a
+//! [`str`]. A component parser returns [`None`] if the position isn't on a
+//! character boundary.
+//!
+//! ```rust
+//! use chrono::NaiveDate;
+//! use whatwg_datetime::parse_date_component;
+//!
+//! let s = "\u{65E5}2011-11-18";
+//! let mut position = '\u{65E5}'.len_utf8();
+//! assert_eq!(parse_date_component(s, &mut position), NaiveDate::from_ymd_opt(2011, 11, 18));
+//! assert_eq!(position, s.len());
+//! ```
+//!
+//! ## Surrounding whitespace
+//!
+//! The WHATWG HTML Standard rejects leading and trailing whitespace, so the
+//! top-level parsers (e.g [`parse_date`]) do too. Form field values frequently
+//! contain surrounding whitespace though, so each top-level parser has a
+//! `*_trimmed` variant (e.g [`parse_date_trimmed`]) which strips leading and
+//! trailing ASCII whitespace before parsing.
+//!
+//! ```rust
+//! use chrono::NaiveDate;
+//! use whatwg_datetime::{parse_date, parse_date_trimmed};
+//!
+//! assert_eq!(parse_date_trimmed("  2011-11-18\n"), NaiveDate::from_ymd_opt(2011, 11, 18));
+//! assert_eq!(parse_date("  2011-11-18\n"), None);
+//! ```
 
+#[cfg(feature = "arbitrary")]
+mod arbitrary;
 mod components;
 mod utils;
 
 pub use crate::components::*;
+pub use crate::utils::{collect_fixed_digits, collect_integer};
 
 pub type ParseStringFn<T> = dyn Fn(&str) -> Option<T>;
 pub type ParseComponentFn<T> = dyn Fn(&str, &mut usize) -> Option<T>;
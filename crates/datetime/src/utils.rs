@@ -1,5 +1,5 @@
 use chrono::{Datelike, NaiveDate, Weekday};
-use whatwg_infra::collect_codepoints;
+use whatwg_infra::{collect_codepoints, skip_ascii_whitespace};
 
 #[inline]
 pub(crate) fn is_valid_month(month: &u32) -> bool {
@@ -18,7 +18,156 @@ pub(crate) fn is_valid_min_or_sec(val: &u32) -> bool {
 
 #[inline]
 pub(crate) fn collect_ascii_digits(s: &str, position: &mut usize) -> String {
-	collect_codepoints(s, position, |c| c.is_ascii_digit())
+	collect_codepoints_at(s, position, |c| c.is_ascii_digit())
+}
+
+// The datetime parsers track `position` as a byte index, while the whatwg-infra
+// helpers track it as a codepoint index. These wrappers run an infra helper on the
+// rest of the string, and advance `position` by the bytes it consumed. Nothing is
+// consumed if `position` isn't on a character boundary.
+
+#[inline]
+pub(crate) fn collect_codepoints_at<P>(s: &str, position: &mut usize, predicate: P) -> String
+where
+	P: Fn(char) -> bool,
+{
+	let rest = match s.get(*position..) {
+		Some(rest) => rest,
+		None => return String::new(),
+	};
+
+	let collected = collect_codepoints(rest, &mut 0, predicate);
+	*position += collected.len();
+	collected
+}
+
+#[inline]
+pub(crate) fn skip_ascii_whitespace_at(s: &str, position: &mut usize) {
+	let rest = match s.get(*position..) {
+		Some(rest) => rest,
+		None => return,
+	};
+
+	// ASCII whitespace is a single byte, so the codepoints skipped are also bytes
+	let mut skipped = 0usize;
+	skip_ascii_whitespace(rest, &mut skipped);
+	*position += skipped;
+}
+
+/// Collects a sequence of ASCII digits as a slice of the input, without allocating.
+///
+/// Nothing is collected if `position` is past the end of the string, or isn't
+/// on a character boundary.
+#[inline]
+pub(crate) fn collect_ascii_digits_str<'a>(s: &'a str, position: &mut usize) -> &'a str {
+	let rest = match s.get(*position..) {
+		Some(rest) => rest,
+		None => return "",
+	};
+
+	let len = rest.bytes().take_while(|b| b.is_ascii_digit()).count();
+	*position += len;
+	&rest[..len]
+}
+
+/// Collects a sequence of ASCII digits at a given position as an integer,
+/// also returning the number of digits that were collected
+///
+/// This returns [`None`] if:
+/// - there are fewer than `min_digits` or more than `max_digits` digits
+/// - `allow_leading_zeros` is false, and there are more than `min_digits`
+///   digits starting with a zero (a zero is only allowed as padding)
+/// - the integer doesn't fit in a `u32`
+///
+/// > **Note**:
+/// > This is the shared building block of the component parsers, such as
+/// > [`parse_month_component`](crate::parse_month_component).
+///
+/// # Examples
+/// ```
+/// use whatwg_datetime::collect_integer;
+///
+/// // A month is exactly two digits
+/// let mut position = 5usize;
+/// assert_eq!(collect_integer("2011-09", &mut position, 2, 2, true), Some((9, 2)));
+/// assert_eq!(position, 7);
+///
+/// // A year is at least four digits
+/// let mut position = 0usize;
+/// assert_eq!(collect_integer("0099-09", &mut position, 4, usize::MAX, false), Some((99, 4)));
+///
+/// let mut position = 0usize;
+/// assert_eq!(collect_integer("02011-09", &mut position, 4, usize::MAX, false), None);
+/// ```
+pub fn collect_integer(
+	s: &str,
+	position: &mut usize,
+	min_digits: usize,
+	max_digits: usize,
+	allow_leading_zeros: bool,
+) -> Option<(u32, usize)> {
+	let digits = collect_ascii_digits_str(s, position);
+	let len = digits.len();
+	if len < min_digits || len > max_digits {
+		return None;
+	}
+
+	if !allow_leading_zeros && len > min_digits && digits.starts_with('0') {
+		return None;
+	}
+
+	Some((digits.parse::<u32>().ok()?, len))
+}
+
+/// Collects exactly `N` ASCII digits at a given position, returning
+/// the value (0-9) of each digit
+///
+/// This returns [`None`] if fewer than `N` ASCII digits are available at the
+/// position, in which case the position isn't advanced. Any digits after the
+/// first `N` are left for the caller to handle.
+///
+/// # Examples
+/// ```
+/// use whatwg_datetime::collect_fixed_digits;
+///
+/// let mut position = 5usize;
+/// assert_eq!(collect_fixed_digits::<2>("2011-09", &mut position), Some([0, 9]));
+/// assert_eq!(position, 7);
+///
+/// let mut position = 5usize;
+/// assert_eq!(collect_fixed_digits::<2>("2011-9", &mut position), None);
+/// assert_eq!(position, 5);
+/// ```
+pub fn collect_fixed_digits<const N: usize>(s: &str, position: &mut usize) -> Option<[u8; N]> {
+	let bytes = s.as_bytes().get(*position..)?.get(..N)?;
+	let mut digits = [0u8; N];
+	for (digit, &b) in digits.iter_mut().zip(bytes) {
+		if !b.is_ascii_digit() {
+			return None;
+		}
+
+		*digit = b - b'0';
+	}
+
+	*position += N;
+	Some(digits)
+}
+
+/// Returns the ASCII character at a given byte position, without walking the string.
+///
+/// A byte which isn't ASCII is part of a multi-byte code point, and never
+/// matches a delimiter, so this returns [`None`] for it.
+#[inline]
+pub(crate) fn ascii_char_at(s: &str, position: usize) -> Option<char> {
+	s.as_bytes()
+		.get(position)
+		.filter(|b| b.is_ascii())
+		.map(|&b| char::from(b))
+}
+
+#[inline]
+pub(crate) const fn is_leap_year(year: i32) -> bool {
+	year % 400 == 0 || (year % 4 == 0 && year % 100 != 0)
 }
 
 pub const fn max_days_in_month_year(month: u32, year: u32) -> Option<u32> {
@@ -26,7 +175,9 @@ pub const fn max_days_in_month_year(month: u32, year: u32) -> Option<u32> {
 		1 | 3 | 5 | 7 | 8 | 10 | 12 => Some(31),
 		4 | 6 | 9 | 11 => Some(30),
 		2 => {
-			if year % 400 == 0 || (year % 4 == 0 && year % 100 != 0) {
+			if year.is_multiple_of(400)
+				|| (year.is_multiple_of(4) && !year.is_multiple_of(100))
+			{
 				Some(29)
 			} else {
 				Some(28)
@@ -38,15 +189,15 @@ pub const fn max_days_in_month_year(month: u32, year: u32) -> Option<u32> {
 
 // https://html.spec.whatwg.org/multipage/common-microsyntaxes.html#weeks
 pub fn week_number_of_year(year: i32) -> Option<u32> {
-	// We call unwrap() here since `NaiveDate::from_ymd_opt` returns `None` only
-	// if the month/day are out-of-range, which is not possible here since they're hardcoded.
-	let naive_date = NaiveDate::from_ymd_opt(year, 1u32, 1u32).unwrap();
+	// `NaiveDate::from_ymd_opt` only returns `None` here if the year is outside
+	// of the range chrono supports, since the month/day are hardcoded.
+	let naive_date = NaiveDate::from_ymd_opt(year, 1u32, 1u32)?;
 	let weekday = naive_date.weekday();
 
 	match weekday {
 		Weekday::Thu => Some(53u32),
 		Weekday::Wed => {
-			if year % 400 == 0 || (year % 4 == 0 && year % 100 != 0) {
+			if is_leap_year(year) {
 				Some(53u32)
 			} else {
 				Some(52u32)
@@ -58,7 +209,132 @@ pub fn week_number_of_year(year: i32) -> Option<u32> {
 
 #[cfg(test)]
 mod tests {
-	use super::{max_days_in_month_year, week_number_of_year};
+	use super::{
+		collect_ascii_digits_str, collect_fixed_digits, collect_integer,
+		max_days_in_month_year, week_number_of_year,
+	};
+	use chrono::{Datelike, NaiveDate, Weekday};
+
+	#[test]
+	fn test_collect_ascii_digits_str() {
+		let mut position = 0usize;
+		assert_eq!(collect_ascii_digits_str("2004-W53", &mut position), "2004");
+		assert_eq!(position, 4);
+		assert_eq!(collect_ascii_digits_str("2004-W53", &mut position), "");
+		assert_eq!(position, 4);
+
+		let mut position = 6usize;
+		assert_eq!(collect_ascii_digits_str("2004-W53", &mut position), "53");
+		assert_eq!(position, 8);
+		assert_eq!(collect_ascii_digits_str("2004-W53", &mut position), "");
+	}
+
+	#[test]
+	fn test_collect_ascii_digits_str_not_char_boundary() {
+		let mut position = 1usize;
+		assert_eq!(collect_ascii_digits_str("\u{E9}12", &mut position), "");
+		assert_eq!(position, 1);
+
+		let mut position = 2usize;
+		assert_eq!(collect_ascii_digits_str("\u{E9}12", &mut position), "12");
+		assert_eq!(position, 4);
+
+		let mut position = 10usize;
+		assert_eq!(collect_ascii_digits_str("12", &mut position), "");
+		assert_eq!(position, 10);
+	}
+
+	#[test]
+	fn test_collect_integer_exact_width() {
+		let mut position = 0usize;
+		assert_eq!(
+			collect_integer("09", &mut position, 2, 2, true),
+			Some((9, 2))
+		);
+		assert_eq!(position, 2);
+
+		let mut position = 0usize;
+		assert_eq!(collect_integer("9", &mut position, 2, 2, true), None);
+
+		let mut position = 0usize;
+		assert_eq!(collect_integer("009", &mut position, 2, 2, true), None);
+	}
+
+	#[test]
+	fn test_collect_integer_variable_width() {
+		let mut position = 0usize;
+		assert_eq!(
+			collect_integer("2011-", &mut position, 4, usize::MAX, false),
+			Some((2011, 4))
+		);
+		assert_eq!(position, 4);
+
+		let mut position = 0usize;
+		assert_eq!(
+			collect_integer("275760-", &mut position, 4, usize::MAX, false),
+			Some((275760, 6))
+		);
+
+		let mut position = 0usize;
+		assert_eq!(
+			collect_integer("201-", &mut position, 4, usize::MAX, false),
+			None
+		);
+	}
+
+	#[test]
+	fn test_collect_integer_leading_zeros() {
+		let mut position = 0usize;
+		assert_eq!(
+			collect_integer("0099", &mut position, 4, usize::MAX, false),
+			Some((99, 4))
+		);
+
+		let mut position = 0usize;
+		assert_eq!(
+			collect_integer("02011", &mut position, 4, usize::MAX, false),
+			None
+		);
+
+		let mut position = 0usize;
+		assert_eq!(
+			collect_integer("02011", &mut position, 4, usize::MAX, true),
+			Some((2011, 5))
+		);
+	}
+
+	#[test]
+	fn test_collect_integer_fails_overflow() {
+		let mut position = 0usize;
+		assert_eq!(
+			collect_integer("99999999999", &mut position, 1, usize::MAX, true),
+			None
+		);
+	}
+
+	#[test]
+	fn test_collect_integer_fails_empty() {
+		let mut position = 0usize;
+		assert_eq!(collect_integer("", &mut position, 1, 2, true), None);
+
+		let mut position = 0usize;
+		assert_eq!(collect_integer("ab", &mut position, 1, 2, true), None);
+		assert_eq!(position, 0);
+	}
+
+	#[test]
+	fn test_collect_integer_multibyte_prefix() {
+		let mut position = 1usize;
+		assert_eq!(collect_integer("\u{E9}12", &mut position, 1, 4, true), None);
+		assert_eq!(position, 1);
+
+		let mut position = 2usize;
+		assert_eq!(
+			collect_integer("\u{E9}12", &mut position, 1, 4, true),
+			Some((12, 2))
+		);
+		assert_eq!(position, 4);
+	}
 
 	#[test]
 	fn test_max_days_in_month_28_days() {
@@ -75,6 +351,14 @@ mod tests {
 		assert_eq!(max_days_in_month_year(2, 2400), Some(29));
 	}
 
+	#[test]
+	fn test_max_days_in_month_years_past_i32_max() {
+		assert_eq!(max_days_in_month_year(2, 2_147_483_648), Some(29));
+		assert_eq!(max_days_in_month_year(2, 2_147_483_700), Some(28));
+		assert_eq!(max_days_in_month_year(2, 4_000_000_000), Some(29));
+		assert_eq!(max_days_in_month_year(2, 4_000_000_100), Some(28));
+	}
+
 	#[test]
 	fn test_max_days_in_month_30_days() {
 		assert_eq!(max_days_in_month_year(4, 2021), Some(30));
@@ -128,4 +412,70 @@ mod tests {
 		assert_eq!(week_number_of_year(2014), Some(52));
 		assert_eq!(week_number_of_year(2025), Some(52));
 	}
+
+	/// Cross-check against chrono's ISO 8601 week dates: a year has 53 weeks
+	/// if and only if week 53 exists, and December 28th always falls in the
+	/// last week of its year
+	#[test]
+	fn test_week_number_of_year_matches_chrono_iso_weeks() {
+		for year in 1995..=2035 {
+			let expected_weeks =
+				match NaiveDate::from_isoywd_opt(year, 53, Weekday::Mon) {
+					Some(_) => 53,
+					None => 52,
+				};
+			let last_week = NaiveDate::from_ymd_opt(year, 12, 28)
+				.unwrap()
+				.iso_week()
+				.week();
+
+			assert_eq!(week_number_of_year(year), Some(expected_weeks), "{year}");
+			assert_eq!(week_number_of_year(year), Some(last_week), "{year}");
+		}
+	}
+
+	#[test]
+	fn test_collect_fixed_digits_two() {
+		let mut position = 0usize;
+		assert_eq!(
+			collect_fixed_digits::<2>("18T", &mut position),
+			Some([1, 8])
+		);
+		assert_eq!(position, 2);
+
+		let mut position = 1usize;
+		assert_eq!(
+			collect_fixed_digits::<2>("-123", &mut position),
+			Some([1, 2])
+		);
+		assert_eq!(position, 3);
+	}
+
+	#[test]
+	fn test_collect_fixed_digits_four() {
+		let mut position = 0usize;
+		assert_eq!(
+			collect_fixed_digits::<4>("2011-11", &mut position),
+			Some([2, 0, 1, 1])
+		);
+		assert_eq!(position, 4);
+	}
+
+	#[test]
+	fn test_collect_fixed_digits_fails() {
+		let mut position = 0usize;
+		assert_eq!(collect_fixed_digits::<4>("201", &mut position), None);
+		assert_eq!(position, 0);
+
+		let mut position = 0usize;
+		assert_eq!(collect_fixed_digits::<4>("20a1", &mut position), None);
+		assert_eq!(position, 0);
+
+		let mut position = 5usize;
+		assert_eq!(collect_fixed_digits::<2>("2011", &mut position), None);
+		assert_eq!(position, 5);
+
+		let mut position = 0usize;
+		assert_eq!(collect_fixed_digits::<2>("é1", &mut position), None);
+	}
 }
@@ -0,0 +1,106 @@
+use chrono::{NaiveTime, Timelike};
+use proptest::prelude::*;
+use whatwg_datetime::{
+	parse_date, parse_global_datetime, parse_local_datetime, parse_month, parse_time,
+	parse_timezone_offset, parse_week, parse_yearless_date, YearMonth, YearWeek, YearlessDate,
+};
+
+const MAX_OFFSET_MINUTES: i32 = 23 * 60 + 59;
+
+fn year_month() -> impl Strategy<Value = YearMonth> {
+	(1i32..=99999, 1u32..=12).prop_map(|(year, month)| YearMonth::new_opt(year, month).unwrap())
+}
+
+fn year_week() -> impl Strategy<Value = YearWeek> {
+	(1i32..=99999, 1u32..=53).prop_filter_map("week out of range for year", |(year, week)| {
+		YearWeek::new_opt(year, week)
+	})
+}
+
+fn yearless_date() -> impl Strategy<Value = YearlessDate> {
+	(1u32..=12, 1u32..=31).prop_filter_map("day out of range for month", |(month, day)| {
+		YearlessDate::new_opt(month, day)
+	})
+}
+
+fn naive_time() -> impl Strategy<Value = NaiveTime> {
+	(0u32..24, 0u32..60, 0u32..60, 0u32..1000).prop_map(|(hour, minute, second, milli)| {
+		NaiveTime::from_hms_milli_opt(hour, minute, second, milli).unwrap()
+	})
+}
+
+fn offset_minutes() -> impl Strategy<Value = i32> {
+	-MAX_OFFSET_MINUTES..=MAX_OFFSET_MINUTES
+}
+
+fn datetime_like_string() -> impl Strategy<Value = String> {
+	"[0-9WTZtz:+\\-. ]{0,32}"
+}
+
+fn serialize_offset_minutes(total: i32) -> String {
+	let sign = if total < 0 { '-' } else { '+' };
+	let total = total.abs();
+	format!("{}{:02}:{:02}", sign, total / 60, total % 60)
+}
+
+proptest! {
+	#[test]
+	fn roundtrip_year_month(value in year_month()) {
+		let s = format!("{:04}-{:02}", value.year(), value.month());
+		prop_assert_eq!(parse_month(&s), Some(value));
+	}
+
+	#[test]
+	fn roundtrip_year_week(value in year_week()) {
+		let s = format!("{:04}-W{:02}", value.year(), value.week());
+		prop_assert_eq!(parse_week(&s), Some(value));
+	}
+
+	#[test]
+	fn roundtrip_yearless_date(value in yearless_date()) {
+		let s = format!("{:02}-{:02}", value.month(), value.day());
+		prop_assert_eq!(parse_yearless_date(&s), Some(value));
+	}
+
+	#[test]
+	fn roundtrip_naive_time(value in naive_time()) {
+		let s = format!(
+			"{:02}:{:02}:{:02}.{:03}",
+			value.hour(),
+			value.minute(),
+			value.second(),
+			value.nanosecond() / 1_000_000
+		);
+		prop_assert_eq!(parse_time(&s), Some(value));
+	}
+
+	#[test]
+	fn roundtrip_timezone_offset(total in offset_minutes()) {
+		let parsed = parse_timezone_offset(&serialize_offset_minutes(total));
+		prop_assert_eq!(parsed.map(|offset| offset.hour() * 60 + offset.minute()), Some(total));
+	}
+
+	#[test]
+	fn parsers_never_panic(s in any::<String>()) {
+		let _ = parse_date(&s);
+		let _ = parse_global_datetime(&s);
+		let _ = parse_local_datetime(&s);
+		let _ = parse_month(&s);
+		let _ = parse_time(&s);
+		let _ = parse_timezone_offset(&s);
+		let _ = parse_week(&s);
+		let _ = parse_yearless_date(&s);
+	}
+
+	#[test]
+	fn parsers_never_panic_datetime_like(s in datetime_like_string()) {
+		let _ = parse_date(&s);
+		let _ = parse_global_datetime(&s);
+		let _ = parse_local_datetime(&s);
+		let _ = parse_month(&s);
+		let _ = parse_time(&s);
+		let _ = parse_timezone_offset(&s);
+		let _ = parse_week(&s);
+		let _ = parse_yearless_date(&s);
+	}
+}
@@ -0,0 +1,677 @@
+extern crate alloc;
+use crate::InfraDecodeError;
+use alloc::string::String;
+use alloc::vec::Vec;
+
+/// Methods from the WHATWG Infra Standard for byte sequences
+pub trait InfraBytes {
+	/// See the documentation for [`byte_lowercase()`]
+	fn byte_lowercase(&self) -> Vec<u8>;
+	/// See the documentation for [`byte_uppercase()`]
+	fn byte_uppercase(&self) -> Vec<u8>;
+	/// See the documentation for [`is_byte_prefix()`]
+	fn is_byte_prefix_of(&self, seq: &[u8]) -> bool;
+	/// See the documentation for [`byte_less_than()`]
+	fn byte_less_than(&self, other: &[u8]) -> bool;
+	/// See the documentation for [`encode_hex()`]
+	fn encode_hex(&self) -> String;
+	/// See the documentation for [`utf8_decode_without_bom_or_fail()`]
+	fn utf8_decode_without_bom_or_fail(&self) -> Option<String>;
+}
+
+impl InfraBytes for [u8] {
+	fn byte_lowercase(&self) -> Vec<u8> {
+		byte_lowercase(self)
+	}
+
+	fn byte_uppercase(&self) -> Vec<u8> {
+		byte_uppercase(self)
+	}
+
+	fn is_byte_prefix_of(&self, seq: &[u8]) -> bool {
+		is_byte_prefix(self, seq)
+	}
+
+	fn byte_less_than(&self, other: &[u8]) -> bool {
+		byte_less_than(self, other)
+	}
+
+	fn encode_hex(&self) -> String {
+		encode_hex(self)
+	}
+
+	fn utf8_decode_without_bom_or_fail(&self) -> Option<String> {
+		utf8_decode_without_bom_or_fail(self)
+	}
+}
+
+/// Replaces each byte in the range 0x41 (A) to 0x5A (Z), inclusive,
+/// with its corresponding byte in the range 0x61 (a) to 0x7A (z), inclusive.
+///
+/// See also: [WHATWG Infra Standard definition][whatwg-infra-dfn]
+///
+/// [whatwg-infra-dfn]: https://infra.spec.whatwg.org/#byte-lowercase
+///
+/// # Examples
+/// ```
+/// use whatwg_infra::byte_lowercase;
+///
+/// assert_eq!(byte_lowercase(b"Text/HTML"), b"text/html".to_vec());
+/// ```
+#[must_use]
+pub fn byte_lowercase(b: &[u8]) -> Vec<u8> {
+	b.iter().map(u8::to_ascii_lowercase).collect()
+}
+
+/// Replaces each byte in the range 0x61 (a) to 0x7A (z), inclusive,
+/// with its corresponding byte in the range 0x41 (A) to 0x5A (Z), inclusive.
+///
+/// See also: [WHATWG Infra Standard definition][whatwg-infra-dfn]
+///
+/// [whatwg-infra-dfn]: https://infra.spec.whatwg.org/#byte-uppercase
+///
+/// # Examples
+/// ```
+/// use whatwg_infra::byte_uppercase;
+///
+/// assert_eq!(byte_uppercase(b"Text/HTML"), b"TEXT/HTML".to_vec());
+/// ```
+#[must_use]
+pub fn byte_uppercase(b: &[u8]) -> Vec<u8> {
+	b.iter().map(u8::to_ascii_uppercase).collect()
+}
+
+/// Checks if a byte sequence `prefix` is a prefix of a byte sequence `seq`.
+///
+/// Every byte sequence is a prefix of itself, and the empty byte sequence
+/// is a prefix of every byte sequence.
+///
+/// See also: [WHATWG Infra Standard definition][whatwg-infra-dfn]
+///
+/// [whatwg-infra-dfn]: https://infra.spec.whatwg.org/#byte-sequence-prefix
+///
+/// # Examples
+/// ```
+/// use whatwg_infra::is_byte_prefix;
+///
+/// assert!(is_byte_prefix(b"text/", b"text/html"));
+/// assert!(is_byte_prefix(b"", b"text/html"));
+/// assert!(!is_byte_prefix(b"text/html", b"text/"));
+/// ```
+#[must_use]
+pub fn is_byte_prefix(prefix: &[u8], seq: &[u8]) -> bool {
+	seq.starts_with(prefix)
+}
+
+/// Checks if a byte sequence `a` is byte less than a byte sequence `b`.
+///
+/// A byte sequence is byte less than another if it is a prefix of the
+/// other, or if at the first byte at which they differ, its byte is smaller.
+///
+/// See also: [WHATWG Infra Standard definition][whatwg-infra-dfn]
+///
+/// [whatwg-infra-dfn]: https://infra.spec.whatwg.org/#byte-less-than
+///
+/// # Examples
+/// ```
+/// use whatwg_infra::byte_less_than;
+///
+/// assert!(byte_less_than(b"text", b"text/html"));
+/// assert!(byte_less_than(b"text/css", b"text/html"));
+/// assert!(!byte_less_than(b"text/html", b"text/html"));
+/// ```
+#[must_use]
+pub fn byte_less_than(a: &[u8], b: &[u8]) -> bool {
+	if is_byte_prefix(b, a) {
+		return false;
+	}
+
+	if is_byte_prefix(a, b) {
+		return true;
+	}
+
+	// Neither is a prefix of the other, so they must differ at some index
+	// within the bounds of both sequences
+	match a.iter().zip(b.iter()).find(|(x, y)| x != y) {
+		Some((x, y)) => x < y,
+		None => false,
+	}
+}
+
+/// Encodes a byte sequence as a string of lowercase ASCII hex digits,
+/// with two digits per byte.
+///
+/// # Examples
+/// ```
+/// use whatwg_infra::encode_hex;
+///
+/// assert_eq!(encode_hex(b"\x00\x7F\xFF"), "007fff");
+/// assert_eq!(encode_hex(b""), "");
+/// ```
+#[must_use]
+pub fn encode_hex(b: &[u8]) -> String {
+	let mut s = String::with_capacity(b.len() * 2);
+	for &byte in b {
+		push_hex_byte(&mut s, byte, LOWER_HEX_DIGITS);
+	}
+
+	s
+}
+
+/// Decodes a string of ASCII hex digits into a byte sequence,
+/// where each pair of digits forms one byte.
+///
+/// Upper and lowercase hex digits are both accepted. This returns [`None`]
+/// if the string has an odd length, or contains a code point which is not
+/// an ASCII hex digit. See [`decode_hex_result()`] for the reason of a failure.
+///
+/// # Examples
+/// ```
+/// use whatwg_infra::decode_hex;
+///
+/// assert_eq!(decode_hex("007fFF"), Some(vec![0x00, 0x7F, 0xFF]));
+/// assert_eq!(decode_hex("abc"), None);
+/// assert_eq!(decode_hex("zz"), None);
+/// ```
+#[must_use]
+pub fn decode_hex(s: &str) -> Option<Vec<u8>> {
+	decode_hex_result(s).ok()
+}
+
+/// Decodes a string of ASCII hex digits into a byte sequence,
+/// where each pair of digits forms one byte.
+///
+/// This is the same as [`decode_hex()`], but returns an [`InfraDecodeError`]
+/// describing why the string could not be decoded.
+///
+/// # Examples
+/// ```
+/// use whatwg_infra::{decode_hex_result, InfraDecodeError};
+///
+/// assert_eq!(decode_hex_result("007fFF"), Ok(vec![0x00, 0x7F, 0xFF]));
+/// assert_eq!(decode_hex_result("abc"), Err(InfraDecodeError::InvalidHexLength));
+/// assert_eq!(
+///     decode_hex_result("0z"),
+///     Err(InfraDecodeError::InvalidHexDigit { position: 1 })
+/// );
+/// ```
+pub fn decode_hex_result(s: &str) -> Result<Vec<u8>, InfraDecodeError> {
+	let digits = s.as_bytes();
+	if digits.len() % 2 != 0 {
+		return Err(InfraDecodeError::InvalidHexLength);
+	}
+
+	let digit_at = |position: usize| {
+		hex_digit_value(digits[position])
+			.ok_or(InfraDecodeError::InvalidHexDigit { position })
+	};
+
+	(0..digits.len())
+		.step_by(2)
+		.map(|position| Ok((digit_at(position)? << 4) | digit_at(position + 1)?))
+		.collect()
+}
+
+const LOWER_HEX_DIGITS: &[u8; 16] = b"0123456789abcdef";
+const UPPER_HEX_DIGITS: &[u8; 16] = b"0123456789ABCDEF";
+
+#[inline]
+fn push_hex_byte(s: &mut String, byte: u8, digits: &[u8; 16]) {
+	s.push(char::from(digits[usize::from(byte >> 4)]));
+	s.push(char::from(digits[usize::from(byte & 0x0F)]));
+}
+
+#[inline]
+fn hex_digit_value(b: u8) -> Option<u8> {
+	char::from(b).to_digit(16).map(|d| d as u8)
+}
+
+/// Decodes each percent-encoded byte (`%` followed by two ASCII hex digits)
+/// in a byte sequence into the byte it represents.
+///
+/// A `%` which is not followed by two ASCII hex digits is passed through
+/// literally, along with the bytes after it.
+///
+/// See also: [WHATWG URL Standard definition][whatwg-url-dfn]
+///
+/// [whatwg-url-dfn]: https://url.spec.whatwg.org/#percent-decode
+///
+/// # Examples
+/// ```
+/// use whatwg_infra::percent_decode;
+///
+/// assert_eq!(percent_decode(b"%41%42C"), b"ABC".to_vec());
+/// assert_eq!(percent_decode(b"100%"), b"100%".to_vec());
+/// assert_eq!(percent_decode(b"%G0"), b"%G0".to_vec());
+/// ```
+#[must_use]
+pub fn percent_decode(bytes: &[u8]) -> Vec<u8> {
+	let mut output = Vec::with_capacity(bytes.len());
+	let mut i = 0usize;
+	while i < bytes.len() {
+		let byte = bytes[i];
+		let decoded = match (byte, bytes.get(i + 1), bytes.get(i + 2)) {
+			(b'%', Some(&high), Some(&low)) => hex_digit_value(high)
+				.zip(hex_digit_value(low))
+				.map(|(high, low)| (high << 4) | low),
+			_ => None,
+		};
+
+		match decoded {
+			Some(decoded) => {
+				output.push(decoded);
+				i += 3;
+			}
+			None => {
+				output.push(byte);
+				i += 1;
+			}
+		}
+	}
+
+	output
+}
+
+/// Appends a byte to a string as a percent-encoded byte, which is `%`
+/// followed by two uppercase ASCII hex digits.
+///
+/// See also: [WHATWG URL Standard definition][whatwg-url-dfn]
+///
+/// [whatwg-url-dfn]: https://url.spec.whatwg.org/#percent-encode
+///
+/// # Examples
+/// ```
+/// use whatwg_infra::percent_encode_byte;
+///
+/// let mut s = String::from("a");
+/// percent_encode_byte(b' ', &mut s);
+/// percent_encode_byte(0xFF, &mut s);
+/// assert_eq!(s, "a%20%FF");
+/// ```
+#[inline]
+pub fn percent_encode_byte(b: u8, out: &mut String) {
+	out.push('%');
+	push_hex_byte(out, b, UPPER_HEX_DIGITS);
+}
+
+/// Encodes a string into a byte sequence whose bytes are each code point's value.
+///
+/// This returns [`None`] if the string contains a code point greater than U+00FF.
+/// See [`isomorphic_encode_result()`] for the reason of a failure.
+///
+/// See also: [WHATWG Infra Standard definition][whatwg-infra-dfn]
+///
+/// [whatwg-infra-dfn]: https://infra.spec.whatwg.org/#isomorphic-encode
+///
+/// # Examples
+/// ```
+/// use whatwg_infra::isomorphic_encode;
+///
+/// assert_eq!(isomorphic_encode("caf\u{e9}"), Some(b"caf\xE9".to_vec()));
+/// assert_eq!(isomorphic_encode("\u{0100}"), None);
+/// ```
+#[must_use]
+pub fn isomorphic_encode(s: &str) -> Option<Vec<u8>> {
+	isomorphic_encode_result(s).ok()
+}
+
+/// Encodes a string into a byte sequence whose bytes are each code point's value.
+///
+/// This is the same as [`isomorphic_encode()`], but returns an [`InfraDecodeError`]
+/// describing why the string could not be encoded.
+///
+/// # Examples
+/// ```
+/// use whatwg_infra::{isomorphic_encode_result, InfraDecodeError};
+///
+/// assert_eq!(isomorphic_encode_result("caf\u{e9}"), Ok(b"caf\xE9".to_vec()));
+/// assert_eq!(
+///     isomorphic_encode_result("\u{0100}"),
+///     Err(InfraDecodeError::CodePointOutOfRange)
+/// );
+/// ```
+pub fn isomorphic_encode_result(s: &str) -> Result<Vec<u8>, InfraDecodeError> {
+	s.chars()
+		.map(|c| u8::try_from(c).map_err(|_| InfraDecodeError::CodePointOutOfRange))
+		.collect()
+}
+
+/// Decodes a string of base64 into a byte sequence, ignoring ASCII whitespace
+/// and allowing the trailing `=` padding to be omitted.
+///
+/// This returns [`None`] if the string has an invalid length, or contains
+/// a code point outside of the base64 alphabet.
+/// See [`forgiving_base64_decode_result()`] for the reason of a failure.
+///
+/// See also: [WHATWG Infra Standard definition][whatwg-infra-dfn]
+///
+/// [whatwg-infra-dfn]: https://infra.spec.whatwg.org/#forgiving-base64-decode
+///
+/// # Examples
+/// ```
+/// use whatwg_infra::forgiving_base64_decode;
+///
+/// assert_eq!(forgiving_base64_decode("aGk="), Some(b"hi".to_vec()));
+/// assert_eq!(forgiving_base64_decode(" aG k "), Some(b"hi".to_vec()));
+/// assert_eq!(forgiving_base64_decode("a"), None);
+/// ```
+#[must_use]
+pub fn forgiving_base64_decode(s: &str) -> Option<Vec<u8>> {
+	forgiving_base64_decode_result(s).ok()
+}
+
+/// Decodes a string of base64 into a byte sequence, ignoring ASCII whitespace
+/// and allowing the trailing `=` padding to be omitted.
+///
+/// This is the same as [`forgiving_base64_decode()`], but returns an
+/// [`InfraDecodeError`] describing why the string could not be decoded.
+///
+/// # Examples
+/// ```
+/// use whatwg_infra::{forgiving_base64_decode_result, InfraDecodeError};
+///
+/// assert_eq!(forgiving_base64_decode_result("aGk"), Ok(b"hi".to_vec()));
+/// assert_eq!(
+///     forgiving_base64_decode_result("a"),
+///     Err(InfraDecodeError::InvalidBase64Length)
+/// );
+/// assert_eq!(
+///     forgiving_base64_decode_result("aG!="),
+///     Err(InfraDecodeError::InvalidBase64Character { position: 2 })
+/// );
+/// ```
+pub fn forgiving_base64_decode_result(s: &str) -> Result<Vec<u8>, InfraDecodeError> {
+	// Step 1: remove all ASCII whitespace, keeping each code point's position
+	let mut data: Vec<(usize, char)> = s
+		.char_indices()
+		.filter(|(_, c)| !c.is_ascii_whitespace())
+		.collect();
+
+	// Step 2
+	if data.len() % 4 == 0 {
+		for _ in 0..2 {
+			if matches!(data.last(), Some((_, '='))) {
+				data.pop();
+			}
+		}
+	}
+
+	// Step 3
+	if data.len() % 4 == 1 {
+		return Err(InfraDecodeError::InvalidBase64Length);
+	}
+
+	// Step 4, 5, 6
+	let mut output = Vec::with_capacity(data.len() * 3 / 4);
+	let mut buffer = 0u32;
+	let mut buffered_bits = 0u32;
+	for (position, c) in data {
+		let n = base64_value(c)
+			.ok_or(InfraDecodeError::InvalidBase64Character { position })?;
+		buffer = (buffer << 6) | n;
+		buffered_bits += 6;
+
+		if buffered_bits == 24 {
+			output.extend_from_slice(&buffer.to_be_bytes()[1..]);
+			buffer = 0;
+			buffered_bits = 0;
+		}
+	}
+
+	// Step 7
+	match buffered_bits {
+		12 => output.push((buffer >> 4) as u8),
+		18 => output.extend_from_slice(&(buffer >> 2).to_be_bytes()[2..]),
+		_ => {}
+	}
+
+	Ok(output)
+}
+
+#[inline]
+fn base64_value(c: char) -> Option<u32> {
+	match c {
+		'A'..='Z' => Some(c as u32 - 'A' as u32),
+		'a'..='z' => Some(c as u32 - 'a' as u32 + 26),
+		'0'..='9' => Some(c as u32 - '0' as u32 + 52),
+		'+' => Some(62),
+		'/' => Some(63),
+		_ => None,
+	}
+}
+
+/// Decodes a byte sequence as UTF-8 into a string, failing on any invalid
+/// byte sequence. A leading byte order mark is kept, rather than stripped.
+///
+/// This returns [`None`] if the byte sequence isn't valid UTF-8.
+/// See [`utf8_decode_without_bom_or_fail_result()`] for the reason of a failure.
+///
+/// See also: [WHATWG Encoding Standard definition][whatwg-encoding-dfn]
+///
+/// [whatwg-encoding-dfn]: https://encoding.spec.whatwg.org/#utf-8-decode-without-bom-or-fail
+///
+/// # Examples
+/// ```
+/// use whatwg_infra::utf8_decode_without_bom_or_fail;
+///
+/// assert_eq!(utf8_decode_without_bom_or_fail(b"caf\xC3\xA9"), Some("caf\u{e9}".to_owned()));
+/// assert_eq!(utf8_decode_without_bom_or_fail(b"\xEF\xBB\xBFa"), Some("\u{FEFF}a".to_owned()));
+/// assert_eq!(utf8_decode_without_bom_or_fail(b"\xFF"), None);
+/// ```
+#[must_use]
+pub fn utf8_decode_without_bom_or_fail(b: &[u8]) -> Option<String> {
+	utf8_decode_without_bom_or_fail_result(b).ok()
+}
+
+/// Decodes a byte sequence as UTF-8 into a string, failing on any invalid
+/// byte sequence. A leading byte order mark is kept, rather than stripped.
+///
+/// This is the same as [`utf8_decode_without_bom_or_fail()`], but returns an
+/// [`InfraDecodeError`] describing why the byte sequence could not be decoded.
+///
+/// # Examples
+/// ```
+/// use whatwg_infra::{utf8_decode_without_bom_or_fail_result, InfraDecodeError};
+///
+/// assert_eq!(
+///     utf8_decode_without_bom_or_fail_result(b"\xFF"),
+///     Err(InfraDecodeError::InvalidUtf8)
+/// );
+/// ```
+pub fn utf8_decode_without_bom_or_fail_result(b: &[u8]) -> Result<String, InfraDecodeError> {
+	core::str::from_utf8(b)
+		.map(String::from)
+		.map_err(|_| InfraDecodeError::InvalidUtf8)
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use alloc::vec;
+
+	#[test]
+	fn test_byte_lowercase() {
+		assert_eq!(byte_lowercase(b"Hello, World!"), b"hello, world!".to_vec());
+		assert_eq!(b"\xC3\x89A".byte_lowercase(), b"\xC3\x89a".to_vec());
+	}
+
+	#[test]
+	fn test_byte_uppercase() {
+		assert_eq!(byte_uppercase(b"Hello, World!"), b"HELLO, WORLD!".to_vec());
+		assert_eq!(b"\xC3\xA9a".byte_uppercase(), b"\xC3\xA9A".to_vec());
+	}
+
+	#[test]
+	fn test_is_byte_prefix() {
+		assert!(is_byte_prefix(b"", b""));
+		assert!(is_byte_prefix(b"abc", b"abc"));
+		assert!(b"ab".is_byte_prefix_of(b"abc"));
+		assert!(!b"abc".is_byte_prefix_of(b"ab"));
+		assert!(!b"b".is_byte_prefix_of(b"abc"));
+	}
+
+	#[test]
+	fn test_byte_less_than() {
+		assert!(byte_less_than(b"", b"a"));
+		assert!(byte_less_than(b"ab", b"abc"));
+		assert!(byte_less_than(b"abc", b"abd"));
+		assert!(b"A".byte_less_than(b"a"));
+	}
+
+	#[test]
+	fn test_byte_less_than_fails() {
+		assert!(!byte_less_than(b"", b""));
+		assert!(!byte_less_than(b"abc", b"abc"));
+		assert!(!byte_less_than(b"abc", b"ab"));
+		assert!(!b"b".byte_less_than(b"abc"));
+	}
+
+	#[test]
+	fn test_encode_hex() {
+		assert_eq!(encode_hex(b""), "");
+		assert_eq!(encode_hex(b"\x00\x0A\xAB\xFF"), "000aabff");
+		assert_eq!(b"Hi".encode_hex(), "4869");
+	}
+
+	#[test]
+	fn test_decode_hex() {
+		assert_eq!(decode_hex(""), Some(Vec::new()));
+		assert_eq!(decode_hex("000aabff"), Some(vec![0x00, 0x0A, 0xAB, 0xFF]));
+		assert_eq!(decode_hex("000AABFF"), Some(vec![0x00, 0x0A, 0xAB, 0xFF]));
+	}
+
+	#[test]
+	fn test_decode_hex_fails() {
+		assert_eq!(decode_hex("abc"), None);
+		assert_eq!(decode_hex("zz"), None);
+		assert_eq!(decode_hex("0g"), None);
+		assert_eq!(decode_hex("\u{e9}"), None);
+	}
+
+	#[test]
+	fn test_hex_round_trip() {
+		let bytes: Vec<u8> = (0..=255).collect();
+		assert_eq!(decode_hex(&encode_hex(&bytes)), Some(bytes));
+	}
+
+	#[test]
+	fn test_decode_hex_result_fails() {
+		assert_eq!(
+			decode_hex_result("abc"),
+			Err(InfraDecodeError::InvalidHexLength)
+		);
+		assert_eq!(
+			decode_hex_result("zz"),
+			Err(InfraDecodeError::InvalidHexDigit { position: 0 })
+		);
+		assert_eq!(
+			decode_hex_result("0a\u{e9}"),
+			Err(InfraDecodeError::InvalidHexDigit { position: 2 })
+		);
+	}
+
+	#[test]
+	fn test_isomorphic_encode() {
+		assert_eq!(isomorphic_encode(""), Some(Vec::new()));
+		assert_eq!(
+			isomorphic_encode("\u{0}\u{7F}\u{FF}"),
+			Some(vec![0x00, 0x7F, 0xFF])
+		);
+	}
+
+	#[test]
+	fn test_isomorphic_encode_result_fails() {
+		assert_eq!(
+			isomorphic_encode_result("a\u{0100}"),
+			Err(InfraDecodeError::CodePointOutOfRange)
+		);
+		assert_eq!(
+			isomorphic_encode_result("\u{1F600}"),
+			Err(InfraDecodeError::CodePointOutOfRange)
+		);
+	}
+
+	#[test]
+	fn test_forgiving_base64_decode() {
+		assert_eq!(forgiving_base64_decode(""), Some(Vec::new()));
+		assert_eq!(forgiving_base64_decode("YQ=="), Some(b"a".to_vec()));
+		assert_eq!(forgiving_base64_decode("YQ"), Some(b"a".to_vec()));
+		assert_eq!(forgiving_base64_decode("YWI="), Some(b"ab".to_vec()));
+		assert_eq!(forgiving_base64_decode("YWJj"), Some(b"abc".to_vec()));
+		assert_eq!(forgiving_base64_decode("\tYW\nJj "), Some(b"abc".to_vec()));
+		assert_eq!(
+			forgiving_base64_decode("+/+/"),
+			Some(vec![0xFB, 0xFF, 0xBF])
+		);
+	}
+
+	#[test]
+	fn test_forgiving_base64_decode_result_fails() {
+		assert_eq!(
+			forgiving_base64_decode_result("YWJjZ"),
+			Err(InfraDecodeError::InvalidBase64Length)
+		);
+		assert_eq!(
+			forgiving_base64_decode_result("YQ="),
+			Err(InfraDecodeError::InvalidBase64Character { position: 2 })
+		);
+		assert_eq!(
+			forgiving_base64_decode_result("Y =-="),
+			Err(InfraDecodeError::InvalidBase64Character { position: 2 })
+		);
+		assert_eq!(
+			forgiving_base64_decode_result("\u{e9}Q=="),
+			Err(InfraDecodeError::InvalidBase64Character { position: 0 })
+		);
+	}
+
+	#[test]
+	fn test_utf8_decode_without_bom_or_fail() {
+		assert_eq!(
+			utf8_decode_without_bom_or_fail(b"\xEF\xBB\xBFabc"),
+			Some(String::from("\u{FEFF}abc"))
+		);
+		assert_eq!(
+			b"caf\xC3\xA9".utf8_decode_without_bom_or_fail(),
+			Some(String::from("caf\u{e9}"))
+		);
+	}
+
+	#[test]
+	fn test_utf8_decode_without_bom_or_fail_result_fails() {
+		assert_eq!(
+			utf8_decode_without_bom_or_fail_result(b"caf\xC3"),
+			Err(InfraDecodeError::InvalidUtf8)
+		);
+		assert_eq!(
+			utf8_decode_without_bom_or_fail_result(b"\xED\xA0\x80"),
+			Err(InfraDecodeError::InvalidUtf8)
+		);
+	}
+
+	#[test]
+	fn test_percent_decode() {
+		assert_eq!(percent_decode(b""), Vec::new());
+		assert_eq!(percent_decode(b"%41%42C"), b"ABC".to_vec());
+		assert_eq!(percent_decode(b"%e2%82%ac"), "\u{20AC}".as_bytes().to_vec());
+		assert_eq!(percent_decode(b"%%41"), b"%A".to_vec());
+	}
+
+	#[test]
+	fn test_percent_decode_passes_through_invalid() {
+		assert_eq!(percent_decode(b"%"), b"%".to_vec());
+		assert_eq!(percent_decode(b"%4"), b"%4".to_vec());
+		assert_eq!(percent_decode(b"%G0"), b"%G0".to_vec());
+		assert_eq!(percent_decode(b"%0G"), b"%0G".to_vec());
+	}
+
+	#[test]
+	fn test_percent_encode_byte() {
+		let mut s = String::new();
+		for b in [0x00, b'%', b'a', 0xAB] {
+			percent_encode_byte(b, &mut s);
+		}
+		assert_eq!(s, "%00%25%61%AB");
+		assert_eq!(percent_decode(s.as_bytes()), vec![0x00, b'%', b'a', 0xAB]);
+	}
+}
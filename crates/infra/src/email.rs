@@ -0,0 +1,57 @@
+/// Checks if a codepoint is allowed in the local part of a
+/// [valid email address][whatwg-html-dfn], before the `@`.
+///
+/// This is an ASCII alphanumeric, or one of the following symbols:
+/// `` ! # $ % & ' * + / = ? ^ _ ` { | } ~ . - ``
+///
+/// [whatwg-html-dfn]: https://html.spec.whatwg.org/multipage/input.html#valid-e-mail-address
+///
+/// # Examples
+/// ```
+/// use whatwg_infra::email::is_valid_email_atext;
+///
+/// assert!(is_valid_email_atext('a'));
+/// assert!(is_valid_email_atext('+'));
+/// assert!(!is_valid_email_atext('@'));
+/// assert!(!is_valid_email_atext(' '));
+/// ```
+#[must_use]
+#[inline]
+pub const fn is_valid_email_atext(c: char) -> bool {
+	c.is_ascii_alphanumeric()
+		|| matches!(
+			c,
+			'!' | '#'
+				| '$' | '%' | '&' | '\'' | '*'
+				| '+' | '/' | '=' | '?' | '^'
+				| '_' | '`' | '{' | '|' | '}'
+				| '~' | '.' | '-'
+		)
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn test_is_valid_email_atext_alphanumeric() {
+		assert!(is_valid_email_atext('a'));
+		assert!(is_valid_email_atext('Z'));
+		assert!(is_valid_email_atext('0'));
+		assert!(is_valid_email_atext('9'));
+	}
+
+	#[test]
+	fn test_is_valid_email_atext_symbols() {
+		for c in "!#$%&'*+/=?^_`{|}~.-".chars() {
+			assert!(is_valid_email_atext(c), "{:?}", c);
+		}
+	}
+
+	#[test]
+	fn test_is_valid_email_atext_fails() {
+		for c in "@ \"(),:;<>[\\]\t\n\u{0}\u{7F}\u{E9}".chars() {
+			assert!(!is_valid_email_atext(c), "{:?}", c);
+		}
+	}
+}
@@ -0,0 +1,91 @@
+use core::fmt;
+
+/// An error which can be returned when decoding a string or byte sequence
+///
+/// # Examples
+/// ```
+/// use whatwg_infra::{decode_hex_result, InfraDecodeError};
+///
+/// assert_eq!(
+///     decode_hex_result("0g"),
+///     Err(InfraDecodeError::InvalidHexDigit { position: 1 })
+/// );
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum InfraDecodeError {
+	/// A code point is greater than U+00FF, and can't be isomorphic encoded
+	CodePointOutOfRange,
+	/// The string has a length which can't be forgiving-base64 decoded
+	InvalidBase64Length,
+	/// A code point isn't part of the base64 alphabet
+	InvalidBase64Character {
+		/// The byte position of the code point
+		position: usize,
+	},
+	/// The string has an odd length, so its hex digits can't be paired into bytes
+	InvalidHexLength,
+	/// A code point isn't an ASCII hex digit
+	InvalidHexDigit {
+		/// The byte position of the code point
+		position: usize,
+	},
+	/// The byte sequence isn't valid UTF-8
+	InvalidUtf8,
+}
+
+impl fmt::Display for InfraDecodeError {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		match self {
+			Self::CodePointOutOfRange => {
+				f.write_str("code point is greater than U+00FF")
+			}
+			Self::InvalidBase64Length => f.write_str("invalid length for base64"),
+			Self::InvalidBase64Character { position } => {
+				write!(f, "invalid base64 character at position {}", position)
+			}
+			Self::InvalidHexLength => f.write_str("odd number of hex digits"),
+			Self::InvalidHexDigit { position } => {
+				write!(f, "invalid hex digit at position {}", position)
+			}
+			Self::InvalidUtf8 => f.write_str("invalid UTF-8"),
+		}
+	}
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for InfraDecodeError {}
+
+#[cfg(test)]
+mod tests {
+	extern crate alloc;
+	use super::*;
+	use alloc::string::ToString;
+
+	#[test]
+	fn test_display() {
+		assert_eq!(
+			InfraDecodeError::InvalidHexDigit { position: 3 }.to_string(),
+			"invalid hex digit at position 3"
+		);
+		assert_eq!(InfraDecodeError::InvalidUtf8.to_string(), "invalid UTF-8");
+	}
+
+	#[cfg(feature = "std")]
+	#[test]
+	fn test_error_trait() {
+		use crate::decode_hex_result;
+		use alloc::{boxed::Box, vec::Vec};
+		use std::error::Error;
+
+		fn decode(s: &str) -> Result<Vec<u8>, Box<dyn Error>> {
+			Ok(decode_hex_result(s)?)
+		}
+
+		assert_eq!(decode("ff00").unwrap(), [0xff, 0x00]);
+		assert_eq!(
+			decode("fg").unwrap_err().to_string(),
+			"invalid hex digit at position 1"
+		);
+	}
+}
@@ -1,5 +1,6 @@
 //! A tiny Rust crate that implements parts of the WHATWG Infra Standard. Specifically, it implements the following:
 //!
+//! - [4.4. Byte sequences](https://infra.spec.whatwg.org/#byte-sequences)
 //! - [4.5. Code points](https://infra.spec.whatwg.org/#code-points)
 //! - [4.6. Strings](https://infra.spec.whatwg.org/#strings)
 //!
@@ -42,8 +43,13 @@
 //! ## no_std
 //!
 //! This crate does not depend on libstd, and can be used in `#![no_std]` environments.
+//! Enabling the optional `std` feature implements `std::error::Error` for
+//! [`InfraDecodeError`].
 #![no_std]
 
+#[cfg(feature = "std")]
+extern crate std;
+
 /// Detection of UTF-16 surrogate codepoints for `u16`
 ///
 /// This module exposes predicate functions for detecting surrogates,
@@ -51,6 +57,18 @@
 pub mod surrogates;
 pub use crate::surrogates::*;
 
+/// Module for byte sequences
+pub mod bytes;
+pub use crate::bytes::*;
+
+/// Module for valid email addresses
+pub mod email;
+pub use crate::email::*;
+
+/// Errors returned by the decoding functions
+pub mod error;
+pub use crate::error::*;
+
 /// Module for Unicode scalar values
 pub mod scalar;
 pub use crate::scalar::*;
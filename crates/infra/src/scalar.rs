@@ -3,12 +3,22 @@
 pub trait InfraScalarValue {
 	/// See the documentation for [`is_ascii_tab_newline()`]
 	fn is_ascii_tab_newline(self) -> bool;
+	/// See the documentation for [`is_ascii_newline()`]
+	fn is_ascii_newline(self) -> bool;
+	/// See the documentation for [`is_ascii_tab()`]
+	fn is_ascii_tab(self) -> bool;
 	/// See the documentation for [`is_c0_control()`]
 	fn is_c0_control(self) -> bool;
 	/// See the documentation for [`is_c0_control_space()`]
 	fn is_c0_control_space(self) -> bool;
 	/// See the documentation for [`is_noncharacter()`]
 	fn is_noncharacter(self) -> bool;
+	/// See the documentation for [`classify_whitespace()`]
+	fn classify_whitespace(self) -> WhitespaceKind;
+	/// See the documentation for [`is_ascii_code_point()`]
+	fn is_ascii_code_point(self) -> bool;
+	/// See the documentation for [`is_scalar_value()`]
+	fn is_scalar_value(self) -> bool;
 }
 
 impl InfraScalarValue for char {
@@ -16,6 +26,14 @@ impl InfraScalarValue for char {
 		is_ascii_tab_newline(self)
 	}
 
+	fn is_ascii_newline(self) -> bool {
+		is_ascii_newline(self)
+	}
+
+	fn is_ascii_tab(self) -> bool {
+		is_ascii_tab(self)
+	}
+
 	fn is_c0_control(self) -> bool {
 		is_c0_control(self)
 	}
@@ -27,6 +45,18 @@ impl InfraScalarValue for char {
 	fn is_noncharacter(self) -> bool {
 		is_noncharacter(self)
 	}
+
+	fn classify_whitespace(self) -> WhitespaceKind {
+		classify_whitespace(self)
+	}
+
+	fn is_ascii_code_point(self) -> bool {
+		is_ascii_code_point(self)
+	}
+
+	fn is_scalar_value(self) -> bool {
+		is_scalar_value(self)
+	}
 }
 
 /// Asserts a codepoint is a "noncharacter" based on a certain range of
@@ -154,6 +184,215 @@ pub const fn is_ascii_tab_newline(c: char) -> bool {
 	matches!(c, '\u{0009}' | '\u{000A}' | '\u{000D}')
 }
 
+/// Checks if a codepoint is equivalent to one of two ASCII newline codepoints
+/// * U+000A LINE FEED (LF)
+/// * U+000D CARRIAGE RETURN (CR)
+///
+/// See also: [WHATWG Infra Standard definition][whatwg-infra-dfn]
+///
+/// [whatwg-infra-dfn]: https://infra.spec.whatwg.org/#newline
+///
+/// # Examples
+/// ```
+/// use whatwg_infra::scalar::is_ascii_newline;
+///
+/// assert!(is_ascii_newline('\r'));
+/// assert!(is_ascii_newline('\n'));
+/// assert!(!is_ascii_newline('\t'));
+/// ```
+#[allow(clippy::wrong_self_convention)]
+#[must_use]
+#[inline]
+pub const fn is_ascii_newline(c: char) -> bool {
+	matches!(c, '\u{000A}' | '\u{000D}')
+}
+
+/// Checks if a codepoint is U+0009 TAB
+///
+/// # Examples
+/// ```
+/// use whatwg_infra::scalar::is_ascii_tab;
+///
+/// assert!(is_ascii_tab('\t'));
+/// assert!(!is_ascii_tab('\n'));
+/// assert!(!is_ascii_tab(' '));
+/// ```
+#[allow(clippy::wrong_self_convention)]
+#[must_use]
+#[inline]
+pub const fn is_ascii_tab(c: char) -> bool {
+	c == '\u{0009}'
+}
+
+/// Checks if a codepoint is within the inclusive range of `lo` to `hi`.
+///
+/// Many predicates in the WHATWG standards are defined as one or more
+/// inclusive ranges of codepoints, such as "a code point in the range
+/// U+0030 (0) to U+0039 (9), inclusive".
+///
+/// # Examples
+/// ```
+/// use whatwg_infra::scalar::in_range;
+///
+/// assert!(in_range('5', '\u{0030}', '\u{0039}'));
+/// assert!(in_range('0', '0', '0'));
+/// assert!(!in_range('a', '\u{0030}', '\u{0039}'));
+/// ```
+#[must_use]
+#[inline]
+pub const fn in_range(c: char, lo: char, hi: char) -> bool {
+	lo <= c && c <= hi
+}
+
+/// Checks if a codepoint is within any of the given inclusive ranges,
+/// where each range is a pair of `(lo, hi)` codepoints.
+///
+/// For predicates known at compile time, see also [`code_point_ranges!`](crate::code_point_ranges).
+///
+/// # Examples
+/// ```
+/// use whatwg_infra::scalar::is_in_ranges;
+///
+/// let ascii_hex_digit = [('0', '9'), ('A', 'F'), ('a', 'f')];
+/// assert!(is_in_ranges('b', &ascii_hex_digit));
+/// assert!(!is_in_ranges('g', &ascii_hex_digit));
+/// assert!(!is_in_ranges('a', &[]));
+/// ```
+#[must_use]
+pub const fn is_in_ranges(c: char, ranges: &[(char, char)]) -> bool {
+	let mut i = 0usize;
+	while i < ranges.len() {
+		if in_range(c, ranges[i].0, ranges[i].1) {
+			return true;
+		}
+		i += 1;
+	}
+
+	false
+}
+
+/// Declares a `const fn` predicate that checks if a codepoint is within
+/// any of the given inclusive ranges of codepoints.
+///
+/// # Examples
+/// ```
+/// use whatwg_infra::code_point_ranges;
+///
+/// code_point_ranges! {
+///     /// An ASCII hex digit
+///     pub fn is_ascii_hex_digit = ['0'..='9', 'A'..='F', 'a'..='f'];
+/// }
+///
+/// assert!(is_ascii_hex_digit('F'));
+/// assert!(!is_ascii_hex_digit('G'));
+/// ```
+#[macro_export]
+macro_rules! code_point_ranges {
+	($(#[$meta:meta])* $vis:vis fn $name:ident = [$($lo:literal..=$hi:literal),+ $(,)?];) => {
+		$(#[$meta])*
+		#[must_use]
+		#[inline]
+		$vis const fn $name(c: char) -> bool {
+			$($crate::scalar::in_range(c, $lo, $hi))||+
+		}
+	};
+}
+
+/// The kind of an ASCII whitespace codepoint, as returned by [`classify_whitespace()`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum WhitespaceKind {
+	/// U+0009 TAB
+	Tab,
+	/// U+000A LF
+	LineFeed,
+	/// U+000C FF
+	FormFeed,
+	/// U+000D CR
+	CarriageReturn,
+	/// U+0020 SPACE
+	Space,
+	/// Not an ASCII whitespace codepoint
+	None,
+}
+
+/// Classifies a codepoint as one of the [ASCII whitespace][whatwg-infra-dfn]
+/// codepoints, or [`WhitespaceKind::None`] if it is not ASCII whitespace.
+///
+/// See also: [WHATWG Infra Standard definition][whatwg-infra-dfn]
+///
+/// [whatwg-infra-dfn]: https://infra.spec.whatwg.org/#ascii-whitespace
+///
+/// # Examples
+/// ```
+/// use whatwg_infra::scalar::{classify_whitespace, WhitespaceKind};
+///
+/// assert_eq!(classify_whitespace('\t'), WhitespaceKind::Tab);
+/// assert_eq!(classify_whitespace('\r'), WhitespaceKind::CarriageReturn);
+/// assert_eq!(classify_whitespace('a'), WhitespaceKind::None);
+/// ```
+#[allow(clippy::wrong_self_convention)]
+#[must_use]
+#[inline]
+pub const fn classify_whitespace(c: char) -> WhitespaceKind {
+	match c {
+		'\u{0009}' => WhitespaceKind::Tab,
+		'\u{000A}' => WhitespaceKind::LineFeed,
+		'\u{000C}' => WhitespaceKind::FormFeed,
+		'\u{000D}' => WhitespaceKind::CarriageReturn,
+		'\u{0020}' => WhitespaceKind::Space,
+		_ => WhitespaceKind::None,
+	}
+}
+
+/// Checks if a codepoint is an ASCII codepoint, which is a codepoint
+/// in the range U+0000 NULL to U+007F DELETE, inclusive.
+///
+/// See also: [WHATWG Infra Standard definition][whatwg-infra-dfn]
+///
+/// [whatwg-infra-dfn]: https://infra.spec.whatwg.org/#ascii-code-point
+///
+/// # Examples
+/// ```
+/// use whatwg_infra::scalar::is_ascii_code_point;
+///
+/// assert!(is_ascii_code_point('\u{0000}'));
+/// assert!(is_ascii_code_point('\u{007F}'));
+/// assert!(!is_ascii_code_point('\u{0080}'));
+/// ```
+#[allow(clippy::wrong_self_convention)]
+#[must_use]
+#[inline]
+pub const fn is_ascii_code_point(c: char) -> bool {
+	in_range(c, '\u{0000}', '\u{007F}')
+}
+
+/// Checks if a codepoint is a scalar value, which is a codepoint
+/// that is not a surrogate.
+///
+/// This is always `true`, since a [`char`] can never hold a surrogate.
+/// It exists to document intent where the WHATWG standards require a
+/// scalar value; to check a `u32`, use [`char::from_u32`] instead, and to
+/// check a UTF-16 code unit, use [`is_surrogate_utf16()`](crate::is_surrogate_utf16).
+///
+/// See also: [WHATWG Infra Standard definition][whatwg-infra-dfn]
+///
+/// [whatwg-infra-dfn]: https://infra.spec.whatwg.org/#scalar-value
+///
+/// # Examples
+/// ```
+/// use whatwg_infra::scalar::is_scalar_value;
+///
+/// assert!(is_scalar_value('a'));
+/// assert!(is_scalar_value('\u{10FFFF}'));
+/// assert_eq!(char::from_u32(0xD800), None);
+/// ```
+#[allow(clippy::wrong_self_convention)]
+#[must_use]
+#[inline]
+pub const fn is_scalar_value(_c: char) -> bool {
+	true
+}
+
 #[cfg(test)]
 mod test {
 	use super::*;
@@ -179,6 +418,32 @@ mod test {
 		assert!('\u{0019}'.is_c0_control_space());
 	}
 
+	#[test]
+	fn test_is_ascii_newline() {
+		assert!(is_ascii_newline('\u{000A}'));
+		assert!('\u{000D}'.is_ascii_newline());
+		assert!(!is_ascii_newline('\u{0009}'));
+		assert!(!' '.is_ascii_newline());
+	}
+
+	#[test]
+	fn test_is_ascii_tab() {
+		assert!(is_ascii_tab('\u{0009}'));
+		assert!(!'\u{000A}'.is_ascii_tab());
+		assert!(!is_ascii_tab('\u{000D}'));
+		assert!(!' '.is_ascii_tab());
+	}
+
+	#[test]
+	fn test_is_ascii_tab_newline_split() {
+		for c in ['\u{0009}', '\u{000A}', '\u{000D}', ' ', 'a'] {
+			assert_eq!(
+				is_ascii_tab_newline(c),
+				is_ascii_tab(c) || is_ascii_newline(c)
+			);
+		}
+	}
+
 	#[test]
 	fn test_is_ascii_tab_newline() {
 		assert!(is_ascii_tab_newline('\t'));
@@ -186,4 +451,69 @@ mod test {
 		assert!('\n'.is_ascii_tab_newline());
 		assert!(!is_ascii_tab_newline('a'));
 	}
+
+	#[test]
+	fn test_in_range() {
+		assert!(in_range('\u{0030}', '\u{0030}', '\u{0039}'));
+		assert!(in_range('\u{0039}', '\u{0030}', '\u{0039}'));
+		assert!(!in_range('\u{002F}', '\u{0030}', '\u{0039}'));
+		assert!(!in_range('\u{003A}', '\u{0030}', '\u{0039}'));
+	}
+
+	#[test]
+	fn test_is_in_ranges() {
+		let ranges = [('\u{0041}', '\u{005A}'), ('\u{0061}', '\u{007A}')];
+		assert!(is_in_ranges('A', &ranges));
+		assert!(is_in_ranges('z', &ranges));
+		assert!(!is_in_ranges('[', &ranges));
+		assert!(!is_in_ranges('`', &ranges));
+	}
+
+	#[test]
+	fn test_code_point_ranges() {
+		code_point_ranges! {
+			fn is_ascii_alpha_or_noncharacter_block = ['A'..='Z', 'a'..='z', '\u{FDD0}'..='\u{FDEF}'];
+		}
+
+		assert!(is_ascii_alpha_or_noncharacter_block('q'));
+		assert!(is_ascii_alpha_or_noncharacter_block('\u{FDD5}'));
+		assert!(!is_ascii_alpha_or_noncharacter_block('0'));
+		assert!(!is_ascii_alpha_or_noncharacter_block('\u{FDF0}'));
+	}
+
+	#[test]
+	fn test_classify_whitespace() {
+		assert_eq!(classify_whitespace('\u{0009}'), WhitespaceKind::Tab);
+		assert_eq!(classify_whitespace('\u{000A}'), WhitespaceKind::LineFeed);
+		assert_eq!(classify_whitespace('\u{000C}'), WhitespaceKind::FormFeed);
+		assert_eq!(
+			'\u{000D}'.classify_whitespace(),
+			WhitespaceKind::CarriageReturn
+		);
+		assert_eq!('\u{0020}'.classify_whitespace(), WhitespaceKind::Space);
+	}
+
+	#[test]
+	fn test_classify_whitespace_none() {
+		assert_eq!(classify_whitespace('a'), WhitespaceKind::None);
+		assert_eq!(classify_whitespace('\u{000B}'), WhitespaceKind::None);
+		assert_eq!(classify_whitespace('\u{00A0}'), WhitespaceKind::None);
+	}
+
+	#[test]
+	fn test_is_ascii_code_point() {
+		assert!(is_ascii_code_point('\u{0000}'));
+		assert!(is_ascii_code_point('a'));
+		assert!('\u{007F}'.is_ascii_code_point());
+		assert!(!'\u{0080}'.is_ascii_code_point());
+		assert!(!is_ascii_code_point('\u{10FFFF}'));
+	}
+
+	#[test]
+	fn test_is_scalar_value() {
+		assert!(is_scalar_value('\u{0000}'));
+		assert!(is_scalar_value('\u{D7FF}'));
+		assert!('\u{E000}'.is_scalar_value());
+		assert!('\u{10FFFF}'.is_scalar_value());
+	}
 }
@@ -1,5 +1,9 @@
 extern crate alloc;
-use alloc::{borrow::ToOwned, string::String};
+use alloc::{
+	borrow::{Cow, ToOwned},
+	string::String,
+	vec::Vec,
+};
 
 /// Methods from the WHATWG Infra Standard for strings
 pub trait InfraStr {
@@ -13,6 +17,15 @@ pub trait InfraStr {
 	fn trim_collapse_ascii_whitespace(&self) -> String;
 	/// See the documentation for [`collect_codepoints()`]
 	fn collect_codepoints<P>(&self, position: &mut usize, predicate: P) -> String
+	where
+		P: Fn(char) -> bool;
+	/// See the documentation for [`collect_codepoints_max()`]
+	fn collect_codepoints_max<P>(
+		&self,
+		position: &mut usize,
+		predicate: P,
+		max: usize,
+	) -> String
 	where
 		P: Fn(char) -> bool;
 	/// See the documentation for [`skip_codepoints()`]
@@ -20,6 +33,76 @@ pub trait InfraStr {
 	where
 		P: Fn(char) -> bool;
 	fn skip_ascii_whitespace(&self, position: &mut usize);
+	/// See the documentation for [`starts_with_ascii_case_insensitive()`]
+	fn starts_with_ascii_case_insensitive(&self, prefix: &str) -> bool;
+	/// See the documentation for [`strip_leading_ascii_whitespace()`]
+	fn strip_leading_ascii_whitespace(&self) -> &str;
+	/// See the documentation for [`strip_trailing_ascii_whitespace()`]
+	fn strip_trailing_ascii_whitespace(&self) -> &str;
+	/// See the documentation for [`find_code_point()`]
+	fn find_code_point(&self, needle: char) -> Option<usize>;
+	/// See the documentation for [`rfind_code_point()`]
+	fn rfind_code_point(&self, needle: char) -> Option<usize>;
+	/// See the documentation for [`chars_with_positions()`]
+	fn chars_with_positions(&self) -> CharsWithPositions<'_>;
+	/// See the documentation for [`collect_until_match()`]
+	fn collect_until_match<P>(&self, position: &mut usize, predicate: P) -> String
+	where
+		P: Fn(char) -> bool;
+	/// See the documentation for [`skip_codepoints_max()`]
+	fn skip_codepoints_max<P>(&self, position: &mut usize, predicate: P, max: usize)
+	where
+		P: Fn(char) -> bool;
+	/// See the documentation for [`find_invalid_code_points()`]
+	fn find_invalid_code_points<P>(&self, is_valid: P) -> Vec<(usize, char)>
+	where
+		P: Fn(char) -> bool;
+	/// See the documentation for [`utf16_len_of_prefix()`]
+	fn utf16_len_of_prefix(&self, byte_position: usize) -> Option<usize>;
+	/// See the documentation for [`collect_codepoints_cow()`]
+	fn collect_codepoints_cow<P>(&self, position: &mut usize, predicate: P) -> Cow<'_, str>
+	where
+		P: Fn(char) -> bool;
+	/// See the documentation for [`strip_utf8_bom()`]
+	fn strip_utf8_bom(&self) -> &str;
+	/// See the documentation for [`split_at_code_point()`]
+	fn split_at_code_point(&self, index: usize) -> Option<(&str, &str)>;
+	/// See the documentation for [`trim_ascii_whitespace_counts()`]
+	fn trim_ascii_whitespace_counts(&self) -> (usize, &str, usize);
+	/// See the documentation for [`collect_codepoints_and_peek()`]
+	fn collect_codepoints_and_peek<P>(
+		&self,
+		position: &mut usize,
+		predicate: P,
+	) -> (&str, Option<char>)
+	where
+		P: Fn(char) -> bool;
+	/// See the documentation for [`ascii_collation_key()`]
+	fn ascii_collation_key(&self) -> String;
+	/// See the documentation for [`whitespace_runs()`]
+	fn whitespace_runs(&self) -> WhitespaceRuns<'_>;
+	/// See the documentation for [`eq_normalized_newlines()`]
+	fn eq_normalized_newlines(&self, other: &str) -> bool;
+	/// See the documentation for [`sanitize()`]
+	fn sanitize<P>(&self, is_allowed: P) -> String
+	where
+		P: Fn(char) -> bool;
+	/// See the documentation for [`code_point_index_to_code_unit_index()`]
+	fn code_point_index_to_code_unit_index(&self, cp_index: usize) -> Option<usize>;
+	/// See the documentation for [`code_unit_index_to_code_point_index()`]
+	fn code_unit_index_to_code_point_index(&self, cu_index: usize) -> Option<usize>;
+	/// See the documentation for [`code_points_equal_with()`]
+	fn code_points_equal_with<F>(&self, other: &str, fold: F) -> bool
+	where
+		F: Fn(char) -> char;
+	/// See the documentation for [`is_ascii_case_insensitive_match()`]
+	fn is_ascii_case_insensitive_match(&self, other: &str) -> bool;
+	/// See the documentation for [`count_code_points_matching()`]
+	fn count_code_points_matching<P>(&self, predicate: P) -> usize
+	where
+		P: Fn(char) -> bool;
+	/// See the documentation for [`normalize_newlines_to()`]
+	fn normalize_newlines_to(&self, target: NewlineStyle) -> String;
 }
 
 impl InfraStr for str {
@@ -46,6 +129,18 @@ impl InfraStr for str {
 		collect_codepoints(self, position, predicate)
 	}
 
+	fn collect_codepoints_max<P>(
+		&self,
+		position: &mut usize,
+		predicate: P,
+		max: usize,
+	) -> String
+	where
+		P: Fn(char) -> bool,
+	{
+		collect_codepoints_max(self, position, predicate, max)
+	}
+
 	fn skip_codepoints<P>(&self, position: &mut usize, predicate: P)
 	where
 		P: Fn(char) -> bool,
@@ -56,6 +151,134 @@ impl InfraStr for str {
 	fn skip_ascii_whitespace(&self, position: &mut usize) {
 		skip_ascii_whitespace(self, position)
 	}
+
+	fn starts_with_ascii_case_insensitive(&self, prefix: &str) -> bool {
+		starts_with_ascii_case_insensitive(self, prefix)
+	}
+
+	fn strip_leading_ascii_whitespace(&self) -> &str {
+		strip_leading_ascii_whitespace(self)
+	}
+
+	fn strip_trailing_ascii_whitespace(&self) -> &str {
+		strip_trailing_ascii_whitespace(self)
+	}
+
+	fn find_code_point(&self, needle: char) -> Option<usize> {
+		find_code_point(self, needle)
+	}
+
+	fn rfind_code_point(&self, needle: char) -> Option<usize> {
+		rfind_code_point(self, needle)
+	}
+
+	fn chars_with_positions(&self) -> CharsWithPositions<'_> {
+		chars_with_positions(self)
+	}
+
+	fn collect_until_match<P>(&self, position: &mut usize, predicate: P) -> String
+	where
+		P: Fn(char) -> bool,
+	{
+		collect_until_match(self, position, predicate)
+	}
+
+	fn skip_codepoints_max<P>(&self, position: &mut usize, predicate: P, max: usize)
+	where
+		P: Fn(char) -> bool,
+	{
+		skip_codepoints_max(self, position, predicate, max)
+	}
+
+	fn find_invalid_code_points<P>(&self, is_valid: P) -> Vec<(usize, char)>
+	where
+		P: Fn(char) -> bool,
+	{
+		find_invalid_code_points(self, is_valid)
+	}
+
+	fn utf16_len_of_prefix(&self, byte_position: usize) -> Option<usize> {
+		utf16_len_of_prefix(self, byte_position)
+	}
+
+	fn collect_codepoints_cow<P>(&self, position: &mut usize, predicate: P) -> Cow<'_, str>
+	where
+		P: Fn(char) -> bool,
+	{
+		collect_codepoints_cow(self, position, predicate)
+	}
+
+	fn strip_utf8_bom(&self) -> &str {
+		strip_utf8_bom(self)
+	}
+
+	fn split_at_code_point(&self, index: usize) -> Option<(&str, &str)> {
+		split_at_code_point(self, index)
+	}
+
+	fn trim_ascii_whitespace_counts(&self) -> (usize, &str, usize) {
+		trim_ascii_whitespace_counts(self)
+	}
+
+	fn collect_codepoints_and_peek<P>(
+		&self,
+		position: &mut usize,
+		predicate: P,
+	) -> (&str, Option<char>)
+	where
+		P: Fn(char) -> bool,
+	{
+		collect_codepoints_and_peek(self, position, predicate)
+	}
+
+	fn ascii_collation_key(&self) -> String {
+		ascii_collation_key(self)
+	}
+
+	fn whitespace_runs(&self) -> WhitespaceRuns<'_> {
+		whitespace_runs(self)
+	}
+
+	fn eq_normalized_newlines(&self, other: &str) -> bool {
+		eq_normalized_newlines(self, other)
+	}
+
+	fn sanitize<P>(&self, is_allowed: P) -> String
+	where
+		P: Fn(char) -> bool,
+	{
+		sanitize(self, is_allowed)
+	}
+
+	fn code_point_index_to_code_unit_index(&self, cp_index: usize) -> Option<usize> {
+		code_point_index_to_code_unit_index(self, cp_index)
+	}
+
+	fn code_unit_index_to_code_point_index(&self, cu_index: usize) -> Option<usize> {
+		code_unit_index_to_code_point_index(self, cu_index)
+	}
+
+	fn code_points_equal_with<F>(&self, other: &str, fold: F) -> bool
+	where
+		F: Fn(char) -> char,
+	{
+		code_points_equal_with(self, other, fold)
+	}
+
+	fn is_ascii_case_insensitive_match(&self, other: &str) -> bool {
+		is_ascii_case_insensitive_match(self, other)
+	}
+
+	fn count_code_points_matching<P>(&self, predicate: P) -> usize
+	where
+		P: Fn(char) -> bool,
+	{
+		count_code_points_matching(self, predicate)
+	}
+
+	fn normalize_newlines_to(&self, target: NewlineStyle) -> String {
+		normalize_newlines_to(self, target)
+	}
 }
 
 impl InfraStr for String {
@@ -82,6 +305,18 @@ impl InfraStr for String {
 		collect_codepoints(self.as_str(), position, predicate)
 	}
 
+	fn collect_codepoints_max<P>(
+		&self,
+		position: &mut usize,
+		predicate: P,
+		max: usize,
+	) -> String
+	where
+		P: Fn(char) -> bool,
+	{
+		collect_codepoints_max(self.as_str(), position, predicate, max)
+	}
+
 	fn skip_codepoints<P>(&self, position: &mut usize, predicate: P)
 	where
 		P: Fn(char) -> bool,
@@ -92,6 +327,159 @@ impl InfraStr for String {
 	fn skip_ascii_whitespace(&self, position: &mut usize) {
 		skip_ascii_whitespace(self.as_str(), position)
 	}
+
+	fn starts_with_ascii_case_insensitive(&self, prefix: &str) -> bool {
+		starts_with_ascii_case_insensitive(self.as_str(), prefix)
+	}
+
+	fn strip_leading_ascii_whitespace(&self) -> &str {
+		strip_leading_ascii_whitespace(self.as_str())
+	}
+
+	fn strip_trailing_ascii_whitespace(&self) -> &str {
+		strip_trailing_ascii_whitespace(self.as_str())
+	}
+
+	fn find_code_point(&self, needle: char) -> Option<usize> {
+		find_code_point(self.as_str(), needle)
+	}
+
+	fn rfind_code_point(&self, needle: char) -> Option<usize> {
+		rfind_code_point(self.as_str(), needle)
+	}
+
+	fn chars_with_positions(&self) -> CharsWithPositions<'_> {
+		chars_with_positions(self.as_str())
+	}
+
+	fn collect_until_match<P>(&self, position: &mut usize, predicate: P) -> String
+	where
+		P: Fn(char) -> bool,
+	{
+		collect_until_match(self.as_str(), position, predicate)
+	}
+
+	fn skip_codepoints_max<P>(&self, position: &mut usize, predicate: P, max: usize)
+	where
+		P: Fn(char) -> bool,
+	{
+		skip_codepoints_max(self.as_str(), position, predicate, max)
+	}
+
+	fn find_invalid_code_points<P>(&self, is_valid: P) -> Vec<(usize, char)>
+	where
+		P: Fn(char) -> bool,
+	{
+		find_invalid_code_points(self.as_str(), is_valid)
+	}
+
+	fn utf16_len_of_prefix(&self, byte_position: usize) -> Option<usize> {
+		utf16_len_of_prefix(self.as_str(), byte_position)
+	}
+
+	fn collect_codepoints_cow<P>(&self, position: &mut usize, predicate: P) -> Cow<'_, str>
+	where
+		P: Fn(char) -> bool,
+	{
+		collect_codepoints_cow(self.as_str(), position, predicate)
+	}
+
+	fn strip_utf8_bom(&self) -> &str {
+		strip_utf8_bom(self.as_str())
+	}
+
+	fn split_at_code_point(&self, index: usize) -> Option<(&str, &str)> {
+		split_at_code_point(self.as_str(), index)
+	}
+
+	fn trim_ascii_whitespace_counts(&self) -> (usize, &str, usize) {
+		trim_ascii_whitespace_counts(self.as_str())
+	}
+
+	fn collect_codepoints_and_peek<P>(
+		&self,
+		position: &mut usize,
+		predicate: P,
+	) -> (&str, Option<char>)
+	where
+		P: Fn(char) -> bool,
+	{
+		collect_codepoints_and_peek(self.as_str(), position, predicate)
+	}
+
+	fn ascii_collation_key(&self) -> String {
+		ascii_collation_key(self.as_str())
+	}
+
+	fn whitespace_runs(&self) -> WhitespaceRuns<'_> {
+		whitespace_runs(self.as_str())
+	}
+
+	fn eq_normalized_newlines(&self, other: &str) -> bool {
+		eq_normalized_newlines(self.as_str(), other)
+	}
+
+	fn sanitize<P>(&self, is_allowed: P) -> String
+	where
+		P: Fn(char) -> bool,
+	{
+		sanitize(self.as_str(), is_allowed)
+	}
+
+	fn code_point_index_to_code_unit_index(&self, cp_index: usize) -> Option<usize> {
+		code_point_index_to_code_unit_index(self.as_str(), cp_index)
+	}
+
+	fn code_unit_index_to_code_point_index(&self, cu_index: usize) -> Option<usize> {
+		code_unit_index_to_code_point_index(self.as_str(), cu_index)
+	}
+
+	fn code_points_equal_with<F>(&self, other: &str, fold: F) -> bool
+	where
+		F: Fn(char) -> char,
+	{
+		code_points_equal_with(self.as_str(), other, fold)
+	}
+
+	fn is_ascii_case_insensitive_match(&self, other: &str) -> bool {
+		is_ascii_case_insensitive_match(self.as_str(), other)
+	}
+
+	fn count_code_points_matching<P>(&self, predicate: P) -> usize
+	where
+		P: Fn(char) -> bool,
+	{
+		count_code_points_matching(self.as_str(), predicate)
+	}
+
+	fn normalize_newlines_to(&self, target: NewlineStyle) -> String {
+		normalize_newlines_to(self.as_str(), target)
+	}
+}
+
+// Whether a codepoint position is at or past the end of a string, so that nothing is left to consume
+#[inline]
+fn at_end(s: &str, position: usize) -> bool {
+	s.chars().nth(position).is_none()
+}
+
+// The rest of a string from a codepoint position, which is empty if the position
+// is at or past the end of the string
+#[inline]
+fn remaining(s: &str, position: usize) -> &str {
+	match s.char_indices().nth(position) {
+		Some((offset, _)) => &s[offset..],
+		None => "",
+	}
+}
+
+// The byte length of the first `count` codepoints of a string, or of the whole
+// string if it has fewer codepoints
+#[inline]
+fn prefix_len(s: &str, count: usize) -> usize {
+	s.char_indices()
+		.nth(count)
+		.map_or(s.len(), |(offset, _)| offset)
 }
 
 /// Replaces every U+000D U+000A pair of codepoints with a single U+000A
@@ -116,6 +504,50 @@ pub fn normalize_newlines(s: &str) -> String {
 		.replace('\u{000D}', "\u{000A}")
 }
 
+/// The style of line break to normalize newlines to, as used by [`normalize_newlines_to()`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum NewlineStyle {
+	/// U+000A LF, which is what [`normalize_newlines()`] uses
+	Lf,
+	/// U+000D CR followed by U+000A LF
+	CrLf,
+	/// U+000D CR
+	Cr,
+}
+
+/// Replaces every U+000D U+000A pair of codepoints, lone U+000D codepoint, and
+/// lone U+000A codepoint with the line break of the given [`NewlineStyle`].
+///
+/// This is the same as [`normalize_newlines()`] when the style is
+/// [`NewlineStyle::Lf`].
+///
+/// # Examples
+/// ```
+/// use whatwg_infra::{normalize_newlines_to, NewlineStyle};
+///
+/// let s = "alice\rbob\r\ncarol\n";
+/// assert_eq!(normalize_newlines_to(s, NewlineStyle::CrLf), "alice\r\nbob\r\ncarol\r\n");
+/// ```
+#[must_use]
+pub fn normalize_newlines_to(s: &str, target: NewlineStyle) -> String {
+	let line_break = match target {
+		NewlineStyle::Lf => "\u{000A}",
+		NewlineStyle::CrLf => "\u{000D}\u{000A}",
+		NewlineStyle::Cr => "\u{000D}",
+	};
+
+	let mut result = String::with_capacity(s.len());
+	for c in normalized_newline_chars(s) {
+		if c == '\u{000A}' {
+			result.push_str(line_break);
+		} else {
+			result.push(c);
+		}
+	}
+
+	result
+}
+
 /// A string without any U+000A LINE FEED (LF) or U+000D CARIAGE RETURN (CR)
 /// codepoints.
 ///
@@ -231,21 +663,55 @@ pub fn collect_codepoints<P>(s: &str, position: &mut usize, predicate: P) -> Str
 where
 	P: Fn(char) -> bool,
 {
-	if s.is_empty() || position >= &mut s.len() {
+	if at_end(s, *position) {
 		return String::new();
 	}
 
-	let mut result = String::with_capacity(s.len() - *position);
+	let rest = remaining(s, *position);
 	let starting_position = *position;
-
 	skip_codepoints(s, position, predicate);
 
-	result.push_str(&s[starting_position..*position]);
-	if result.len() < s.len() - *position {
-		result.shrink_to_fit();
+	rest[..prefix_len(rest, *position - starting_position)].to_owned()
+}
+
+/// Collects a sequence of Unicode codepoints given a predicate function
+/// and position to move forward, stopping after at most `max` codepoints.
+///
+/// This is useful for fixed-width fields, where any matching codepoints
+/// past the maximum should be left for the caller to consume.
+///
+/// # Examples
+/// ```
+/// use whatwg_infra::collect_codepoints_max;
+///
+/// let value = "12345";
+/// let mut position = 0usize;
+/// let collected = collect_codepoints_max(value, &mut position, |c| c.is_ascii_digit(), 2);
+///
+/// assert_eq!(collected, String::from("12"));
+/// assert_eq!(position, 2);
+/// ```
+pub fn collect_codepoints_max<P>(s: &str, position: &mut usize, predicate: P, max: usize) -> String
+where
+	P: Fn(char) -> bool,
+{
+	if at_end(s, *position) {
+		return String::new();
 	}
 
-	result
+	let starting_position = *position;
+	for c in s.chars().skip(starting_position).take(max) {
+		if predicate(c) {
+			*position += 1;
+		} else {
+			break;
+		}
+	}
+
+	s.chars()
+		.skip(starting_position)
+		.take(*position - starting_position)
+		.collect()
 }
 
 /// A non-allocating version of [`collect_codepoints()`] for skipping/ignoring
@@ -267,13 +733,46 @@ pub fn skip_codepoints<P>(s: &str, position: &mut usize, predicate: P)
 where
 	P: Fn(char) -> bool,
 {
-	if s.is_empty() || position >= &mut s.len() {
+	if at_end(s, *position) {
 		return;
 	}
 
 	let rest = s.chars().skip(*position);
 	for c in rest {
-		if position < &mut s.len() && predicate(c) {
+		if predicate(c) {
+			*position += 1;
+		} else {
+			break;
+		}
+	}
+}
+
+/// A non-allocating version of [`collect_codepoints_max()`] for skipping
+/// at most `max` codepoints that match a certain predicate.
+///
+/// # Examples
+/// ```
+/// use whatwg_infra::skip_codepoints_max;
+///
+/// let s = "   alice";
+/// let mut position = 0usize;
+///
+/// skip_codepoints_max(s, &mut position, |c| c == ' ', 1);
+///
+/// assert_eq!(position, 1);
+/// assert_eq!(&s[position..], "  alice");
+/// ```
+pub fn skip_codepoints_max<P>(s: &str, position: &mut usize, predicate: P, max: usize)
+where
+	P: Fn(char) -> bool,
+{
+	if at_end(s, *position) {
+		return;
+	}
+
+	let rest = s.chars().skip(*position).take(max);
+	for c in rest {
+		if predicate(c) {
 			*position += 1;
 		} else {
 			break;
@@ -302,189 +801,1626 @@ pub fn skip_ascii_whitespace(s: &str, position: &mut usize) {
 	skip_codepoints(s, position, |c| c.is_ascii_whitespace())
 }
 
-#[cfg(test)]
-mod test {
-	use super::*;
-
-	#[test]
-	fn test_normalize_newlines() {
-		assert_eq!(
-			"\ralice\r\n\r\nbob\r".normalize_newlines(),
+/// Checks if a string starts with a given prefix, comparing each codepoint
+/// in an [ASCII case-insensitive][whatwg-infra-dfn] manner.
+///
+/// Only the first codepoints of `s` up to the length of `prefix` are compared,
+/// and this does not allocate. If `s` is shorter than `prefix`, this returns `false`.
+///
+/// See also: [WHATWG Infra Standard definition][whatwg-infra-dfn]
+///
+/// [whatwg-infra-dfn]: https://infra.spec.whatwg.org/#ascii-case-insensitive
+///
+/// # Examples
+/// ```
+/// use whatwg_infra::starts_with_ascii_case_insensitive;
+///
+/// assert!(starts_with_ascii_case_insensitive("HTTPS://example.com", "https:"));
+/// assert!(!starts_with_ascii_case_insensitive("http", "https"));
+/// ```
+#[must_use]
+pub fn starts_with_ascii_case_insensitive(s: &str, prefix: &str) -> bool {
+	let mut chars = s.chars();
+	prefix.chars().all(|p| match chars.next() {
+		Some(c) => c.eq_ignore_ascii_case(&p),
+		None => false,
+	})
+}
+
+/// Removes ASCII whitespace from only the start of a string.
+///
+/// See also: [WHATWG Infra Standard definition][whatwg-infra-dfn]
+///
+/// [whatwg-infra-dfn]: https://infra.spec.whatwg.org/#strip-leading-and-trailing-ascii-whitespace
+///
+/// # Examples
+/// ```
+/// use whatwg_infra::strip_leading_ascii_whitespace;
+///
+/// let s = "\t\n cats and dogs  ";
+/// assert_eq!(strip_leading_ascii_whitespace(s), "cats and dogs  ");
+/// ```
+#[must_use]
+pub fn strip_leading_ascii_whitespace(s: &str) -> &str {
+	s.trim_start_matches(|c: char| c.is_ascii_whitespace())
+}
+
+/// Removes ASCII whitespace from only the end of a string.
+///
+/// See also: [WHATWG Infra Standard definition][whatwg-infra-dfn]
+///
+/// [whatwg-infra-dfn]: https://infra.spec.whatwg.org/#strip-leading-and-trailing-ascii-whitespace
+///
+/// # Examples
+/// ```
+/// use whatwg_infra::strip_trailing_ascii_whitespace;
+///
+/// let s = "  cats and dogs \r\n";
+/// assert_eq!(strip_trailing_ascii_whitespace(s), "  cats and dogs");
+/// ```
+#[must_use]
+pub fn strip_trailing_ascii_whitespace(s: &str) -> &str {
+	s.trim_end_matches(|c: char| c.is_ascii_whitespace())
+}
+
+/// Finds the codepoint index of the first occurrence of a codepoint
+/// in a string, or `None` if it doesn't occur.
+///
+/// Unlike [`str::find()`], which returns a byte index, this returns the
+/// index as a number of codepoints, which is how positions are defined
+/// in algorithms of the WHATWG standards.
+///
+/// # Examples
+/// ```
+/// use whatwg_infra::find_code_point;
+///
+/// assert_eq!(find_code_point("日本-語", '-'), Some(2));
+/// assert_eq!("日本-語".find('-'), Some(6));
+/// assert_eq!(find_code_point("日本語", '-'), None);
+/// ```
+#[must_use]
+pub fn find_code_point(s: &str, needle: char) -> Option<usize> {
+	s.chars().position(|c| c == needle)
+}
+
+/// Finds the codepoint index of the last occurrence of a codepoint
+/// in a string, or `None` if it doesn't occur.
+///
+/// Unlike [`str::rfind()`], which returns a byte index, this returns the
+/// index as a number of codepoints.
+///
+/// # Examples
+/// ```
+/// use whatwg_infra::rfind_code_point;
+///
+/// assert_eq!(rfind_code_point("日-本-語", '-'), Some(3));
+/// assert_eq!(rfind_code_point("日本語", '-'), None);
+/// ```
+#[must_use]
+pub fn rfind_code_point(s: &str, needle: char) -> Option<usize> {
+	s.rfind(needle).map(|index| s[..index].chars().count())
+}
+
+/// An iterator over the codepoints of a string, along with both their
+/// byte index and codepoint index.
+///
+/// This is created by [`chars_with_positions()`].
+#[derive(Debug, Clone)]
+pub struct CharsWithPositions<'a> {
+	inner: core::str::CharIndices<'a>,
+	code_point_index: usize,
+}
+
+impl Iterator for CharsWithPositions<'_> {
+	type Item = (usize, usize, char);
+
+	fn next(&mut self) -> Option<Self::Item> {
+		let (byte_index, c) = self.inner.next()?;
+		let code_point_index = self.code_point_index;
+		self.code_point_index += 1;
+
+		Some((byte_index, code_point_index, c))
+	}
+
+	fn size_hint(&self) -> (usize, Option<usize>) {
+		self.inner.size_hint()
+	}
+}
+
+/// Iterates over the codepoints of a string, yielding a tuple of
+/// `(byte_index, code_point_index, char)` for each codepoint.
+///
+/// The byte index is useful for slicing the string, while the codepoint
+/// index corresponds to positions in algorithms of the WHATWG standards.
+///
+/// # Examples
+/// ```
+/// use whatwg_infra::chars_with_positions;
+///
+/// let mut iter = chars_with_positions("é-a");
+/// assert_eq!(iter.next(), Some((0, 0, 'é')));
+/// assert_eq!(iter.next(), Some((2, 1, '-')));
+/// assert_eq!(iter.next(), Some((3, 2, 'a')));
+/// assert_eq!(iter.next(), None);
+/// ```
+pub fn chars_with_positions(s: &str) -> CharsWithPositions<'_> {
+	CharsWithPositions {
+		inner: s.char_indices(),
+		code_point_index: 0,
+	}
+}
+
+/// Replaces each ASCII upper alpha codepoint in a string with its
+/// corresponding ASCII lower alpha codepoint, in place.
+///
+/// This only changes single bytes within the ASCII range, so the string
+/// remains valid UTF-8 and an owned `String` is never reallocated.
+///
+/// See also: [WHATWG Infra Standard definition][whatwg-infra-dfn]
+///
+/// [whatwg-infra-dfn]: https://infra.spec.whatwg.org/#ascii-lowercase
+///
+/// # Examples
+/// ```
+/// use whatwg_infra::ascii_lowercase_in_place;
+///
+/// let mut s = String::from("Text/HTML; Charset=\u{C9}");
+/// ascii_lowercase_in_place(&mut s);
+/// assert_eq!(s, "text/html; charset=\u{C9}");
+/// ```
+#[inline]
+pub fn ascii_lowercase_in_place(s: &mut str) {
+	s.make_ascii_lowercase()
+}
+
+/// Replaces each ASCII lower alpha codepoint in a string with its
+/// corresponding ASCII upper alpha codepoint, in place.
+///
+/// This only changes single bytes within the ASCII range, so the string
+/// remains valid UTF-8 and an owned `String` is never reallocated.
+///
+/// See also: [WHATWG Infra Standard definition][whatwg-infra-dfn]
+///
+/// [whatwg-infra-dfn]: https://infra.spec.whatwg.org/#ascii-uppercase
+///
+/// # Examples
+/// ```
+/// use whatwg_infra::ascii_uppercase_in_place;
+///
+/// let mut s = String::from("Text/HTML; Charset=\u{E9}");
+/// ascii_uppercase_in_place(&mut s);
+/// assert_eq!(s, "TEXT/HTML; CHARSET=\u{E9}");
+/// ```
+#[inline]
+pub fn ascii_uppercase_in_place(s: &mut str) {
+	s.make_ascii_uppercase()
+}
+
+/// Collects a sequence of Unicode codepoints up to the first codepoint
+/// which matches a predicate function, moving the position forward.
+///
+/// This is the inverse of [`collect_codepoints()`], for the common phrasing
+/// of "collect a sequence of code points that are not X". The matching
+/// codepoint is not collected, and the position is left pointing at it.
+///
+/// See also: [WHATWG Infra Standard definition][whatwg-infra-dfn]
+///
+/// [whatwg-infra-dfn]: https://infra.spec.whatwg.org/#collect-a-sequence-of-code-points
+///
+/// # Examples
+/// ```
+/// use whatwg_infra::collect_until_match;
+///
+/// let value = "abc123";
+/// let mut position = 0usize;
+/// let collected = collect_until_match(value, &mut position, |c| c.is_ascii_digit());
+///
+/// assert_eq!(collected, String::from("abc"));
+/// assert_eq!(position, 3);
+/// ```
+pub fn collect_until_match<P>(s: &str, position: &mut usize, predicate: P) -> String
+where
+	P: Fn(char) -> bool,
+{
+	collect_codepoints(s, position, |c| !predicate(c))
+}
+
+/// Finds every codepoint in a string which fails a predicate function,
+/// returning the codepoint index and the codepoint of each.
+///
+/// This is useful for diagnostics which point out each offending codepoint,
+/// rather than only the first.
+///
+/// # Examples
+/// ```
+/// use whatwg_infra::{find_invalid_code_points, is_c0_control};
+///
+/// let invalid = find_invalid_code_points("a\u{0}b\u{1F}c", |c| !is_c0_control(c));
+/// assert_eq!(invalid, vec![(1, '\u{0}'), (3, '\u{1F}')]);
+/// ```
+#[must_use]
+pub fn find_invalid_code_points<P>(s: &str, is_valid: P) -> Vec<(usize, char)>
+where
+	P: Fn(char) -> bool,
+{
+	s.chars()
+		.enumerate()
+		.filter(|&(_, c)| !is_valid(c))
+		.collect()
+}
+
+/// Counts the number of UTF-16 code units in a string before a byte position.
+///
+/// This maps a byte index into a Rust string to the equivalent index into
+/// a JavaScript string, whose indices are based on UTF-16 code units. A
+/// codepoint outside of the Basic Multilingual Plane is 4 bytes in UTF-8,
+/// but 2 code units (a surrogate pair) in UTF-16.
+///
+/// This returns [`None`] if `byte_position` is past the end of the string,
+/// or isn't on a codepoint boundary.
+///
+/// # Examples
+/// ```
+/// use whatwg_infra::utf16_len_of_prefix;
+///
+/// let s = "a\u{1F600}b";
+/// assert_eq!(utf16_len_of_prefix(s, 1), Some(1));
+/// assert_eq!(utf16_len_of_prefix(s, 5), Some(3));
+/// assert_eq!(utf16_len_of_prefix(s, 2), None);
+/// ```
+#[must_use]
+pub fn utf16_len_of_prefix(s: &str, byte_position: usize) -> Option<usize> {
+	s.get(..byte_position)
+		.map(|prefix| prefix.chars().map(char::len_utf16).sum())
+}
+
+/// Collects a sequence of Unicode codepoints given a predicate function
+/// and position to move forward, borrowing from the input.
+///
+/// The collected codepoints are always a contiguous slice of the input,
+/// so unlike [`collect_codepoints()`], this never allocates. Prefer this
+/// when the result is only read.
+///
+/// See also: [WHATWG Infra Standard definition][whatwg-infra-dfn]
+///
+/// [whatwg-infra-dfn]: https://infra.spec.whatwg.org/#collect-a-sequence-of-code-points
+///
+/// # Examples
+/// ```
+/// use std::borrow::Cow;
+/// use whatwg_infra::collect_codepoints_cow;
+///
+/// let value = "test1";
+/// let mut position = 0usize;
+/// let collected = collect_codepoints_cow(value, &mut position, |c| c.is_ascii_alphabetic());
+///
+/// assert_eq!(collected, Cow::Borrowed("test"));
+/// assert_eq!(position, 4);
+/// ```
+#[must_use]
+pub fn collect_codepoints_cow<'a, P>(s: &'a str, position: &mut usize, predicate: P) -> Cow<'a, str>
+where
+	P: Fn(char) -> bool,
+{
+	if at_end(s, *position) {
+		return Cow::Borrowed("");
+	}
+
+	let rest = remaining(s, *position);
+	let starting_position = *position;
+	skip_codepoints(s, position, predicate);
+
+	Cow::Borrowed(&rest[..prefix_len(rest, *position - starting_position)])
+}
+
+/// Removes each codepoint from a string which doesn't match a predicate
+/// function, in place.
+///
+/// This is [`String::retain`], named after the predicates from the
+/// WHATWG Infra Standard (e.g [`is_c0_control()`]) which it's commonly
+/// used with.
+///
+/// [`is_c0_control()`]: crate::is_c0_control
+///
+/// # Examples
+/// ```
+/// use whatwg_infra::{is_c0_control, retain_matching};
+///
+/// let mut s = String::from("a\u{0}b\tc\u{1F}");
+/// retain_matching(&mut s, |c| !is_c0_control(c));
+/// assert_eq!(s, "abc");
+/// ```
+#[inline]
+pub fn retain_matching<P>(s: &mut String, predicate: P)
+where
+	P: Fn(char) -> bool,
+{
+	s.retain(predicate);
+}
+
+/// Removes a leading U+FEFF BYTE ORDER MARK from a string, if present.
+///
+/// Only a single byte order mark is removed, like when
+/// [decoding UTF-8][whatwg-encoding-dfn]. The result is a subslice of the
+/// input, so this never allocates.
+///
+/// [whatwg-encoding-dfn]: https://encoding.spec.whatwg.org/#utf-8-decode
+///
+/// # Examples
+/// ```
+/// use whatwg_infra::strip_utf8_bom;
+///
+/// assert_eq!(strip_utf8_bom("\u{FEFF}cats"), "cats");
+/// assert_eq!(strip_utf8_bom("cats"), "cats");
+/// ```
+#[must_use]
+#[inline]
+pub fn strip_utf8_bom(s: &str) -> &str {
+	s.strip_prefix('\u{FEFF}').unwrap_or(s)
+}
+
+/// Divides a string into two slices at a codepoint index.
+///
+/// The first slice contains the first `index` codepoints, and the second
+/// slice contains the rest. This returns [`None`] if `index` is greater
+/// than the number of codepoints in the string.
+///
+/// # Examples
+/// ```
+/// use whatwg_infra::split_at_code_point;
+///
+/// assert_eq!(split_at_code_point("caf\u{E9}s", 4), Some(("caf\u{E9}", "s")));
+/// assert_eq!(split_at_code_point("caf\u{E9}s", 5), Some(("caf\u{E9}s", "")));
+/// assert_eq!(split_at_code_point("caf\u{E9}s", 6), None);
+/// ```
+#[must_use]
+pub fn split_at_code_point(s: &str, index: usize) -> Option<(&str, &str)> {
+	s.char_indices()
+		.map(|(byte_position, _)| byte_position)
+		.chain(core::iter::once(s.len()))
+		.nth(index)
+		.map(|byte_position| s.split_at(byte_position))
+}
+
+/// Removes ASCII whitespace from before and after a string, also returning
+/// the number of codepoints removed from the start and from the end.
+///
+/// This is useful for parsers which track a position into the original
+/// string, since each position into the trimmed string is offset by the
+/// number of leading codepoints removed.
+///
+/// See also: [WHATWG Infra Standard definition][whatwg-infra-dfn]
+///
+/// [whatwg-infra-dfn]: https://infra.spec.whatwg.org/#strip-leading-and-trailing-ascii-whitespace
+///
+/// # Examples
+/// ```
+/// use whatwg_infra::trim_ascii_whitespace_counts;
+///
+/// assert_eq!(trim_ascii_whitespace_counts("  abc   "), (2, "abc", 3));
+/// assert_eq!(trim_ascii_whitespace_counts("abc"), (0, "abc", 0));
+/// ```
+#[must_use]
+pub fn trim_ascii_whitespace_counts(s: &str) -> (usize, &str, usize) {
+	let leading_stripped = strip_leading_ascii_whitespace(s);
+	let trimmed = strip_trailing_ascii_whitespace(leading_stripped);
+
+	// ASCII whitespace codepoints are a single byte each
+	let leading = s.len() - leading_stripped.len();
+	let trailing = leading_stripped.len() - trimmed.len();
+	(leading, trimmed, trailing)
+}
+
+/// Collects a sequence of Unicode codepoints given a predicate function
+/// and position to move forward, also returning the codepoint which
+/// stopped the collection without consuming it.
+///
+/// The stopping codepoint is [`None`] if the end of the string was reached.
+/// Like [`collect_codepoints_cow()`], the collected codepoints are borrowed
+/// from the input.
+///
+/// # Examples
+/// ```
+/// use whatwg_infra::collect_codepoints_and_peek;
+///
+/// let mut position = 0usize;
+/// let (collected, next) = collect_codepoints_and_peek("2011-11", &mut position, |c| c.is_ascii_digit());
+///
+/// assert_eq!(collected, "2011");
+/// assert_eq!(next, Some('-'));
+/// assert_eq!(position, 4);
+/// ```
+#[must_use]
+pub fn collect_codepoints_and_peek<'a, P>(
+	s: &'a str,
+	position: &mut usize,
+	predicate: P,
+) -> (&'a str, Option<char>)
+where
+	P: Fn(char) -> bool,
+{
+	let rest = remaining(s, *position);
+	for (offset, c) in rest.char_indices() {
+		if !predicate(c) {
+			return (&rest[..offset], Some(c));
+		}
+		*position += 1;
+	}
+
+	(rest, None)
+}
+
+/// Creates a key for comparing strings which ignores ASCII case and ASCII
+/// whitespace differences, such as user-entered labels.
+///
+/// The key is the string with its ASCII whitespace
+/// [stripped and collapsed][`trim_collapse_ascii_whitespace()`], and then
+/// [ASCII lowercased][`ascii_lowercase_in_place()`]. Other codepoints are
+/// kept as-is, so this isn't a Unicode normalization.
+///
+/// # Examples
+/// ```
+/// use whatwg_infra::ascii_collation_key;
+///
+/// assert_eq!(ascii_collation_key("  Hello   World "), "hello world");
+/// assert_eq!(ascii_collation_key("  Hello   World "), ascii_collation_key("hello world"));
+/// ```
+#[must_use]
+pub fn ascii_collation_key(s: &str) -> String {
+	let mut key = trim_collapse_ascii_whitespace(s);
+	ascii_lowercase_in_place(&mut key);
+	key
+}
+
+/// An iterator over the maximal runs of ASCII whitespace in a string.
+///
+/// This is created by [`whitespace_runs()`].
+#[derive(Debug, Clone)]
+pub struct WhitespaceRuns<'a> {
+	bytes: &'a [u8],
+	position: usize,
+}
+
+impl Iterator for WhitespaceRuns<'_> {
+	type Item = (usize, usize);
+
+	fn next(&mut self) -> Option<Self::Item> {
+		let rest = self.bytes.get(self.position..)?;
+		let start = self.position + rest.iter().position(u8::is_ascii_whitespace)?;
+		let end = self.bytes[start..]
+			.iter()
+			.position(|b| !b.is_ascii_whitespace())
+			.map_or(self.bytes.len(), |len| start + len);
+		self.position = end;
+
+		Some((start, end))
+	}
+}
+
+/// Iterates over each maximal run of ASCII whitespace in a string, yielding
+/// a tuple of `(start_byte, end_byte)` for each run.
+///
+/// Since ASCII whitespace codepoints are single bytes, both indices are
+/// always on codepoint boundaries, and can be used to slice the string.
+///
+/// See also: [WHATWG Infra Standard definition][whatwg-infra-dfn]
+///
+/// [whatwg-infra-dfn]: https://infra.spec.whatwg.org/#ascii-whitespace
+///
+/// # Examples
+/// ```
+/// use whatwg_infra::whitespace_runs;
+///
+/// let mut runs = whitespace_runs(" cats \t\n and  dogs");
+/// assert_eq!(runs.next(), Some((0, 1)));
+/// assert_eq!(runs.next(), Some((5, 9)));
+/// assert_eq!(runs.next(), Some((12, 14)));
+/// assert_eq!(runs.next(), None);
+/// ```
+pub fn whitespace_runs(s: &str) -> WhitespaceRuns<'_> {
+	WhitespaceRuns {
+		bytes: s.as_bytes(),
+		position: 0,
+	}
+}
+
+/// Checks if two strings are equal after [normalizing their newlines][normalize_newlines()],
+/// without allocating.
+///
+/// Each U+000D U+000A pair of codepoints, lone U+000D codepoint, and lone
+/// U+000A codepoint is treated as the same line break.
+///
+/// # Examples
+/// ```
+/// use whatwg_infra::eq_normalized_newlines;
+///
+/// assert!(eq_normalized_newlines("a\r\nb", "a\nb"));
+/// assert!(eq_normalized_newlines("a\r\nb", "a\rb"));
+/// assert!(!eq_normalized_newlines("a\r\nb", "a\n\nb"));
+/// ```
+#[must_use]
+pub fn eq_normalized_newlines(a: &str, b: &str) -> bool {
+	normalized_newline_chars(a).eq(normalized_newline_chars(b))
+}
+
+// The codepoints of a string, as they would be after normalizing newlines
+fn normalized_newline_chars(s: &str) -> impl Iterator<Item = char> + '_ {
+	let mut chars = s.chars().peekable();
+	core::iter::from_fn(move || match chars.next()? {
+		'\u{000D}' => {
+			chars.next_if_eq(&'\u{000A}');
+			Some('\u{000A}')
+		}
+		c => Some(c),
+	})
+}
+
+/// Replaces every codepoint in a string which isn't allowed by a predicate
+/// function with U+FFFD REPLACEMENT CHARACTER.
+///
+/// This is the counterpart of [`find_invalid_code_points()`], for cleaning
+/// up a string rather than reporting on it.
+///
+/// # Examples
+/// ```
+/// use whatwg_infra::{is_c0_control, sanitize};
+///
+/// let sanitized = sanitize("a\u{0}b\u{1F}c", |c| !is_c0_control(c));
+/// assert_eq!(sanitized, "a\u{FFFD}b\u{FFFD}c");
+/// ```
+#[must_use]
+pub fn sanitize<P>(s: &str, is_allowed: P) -> String
+where
+	P: Fn(char) -> bool,
+{
+	s.chars()
+		.map(|c| {
+			if is_allowed(c) {
+				c
+			} else {
+				char::REPLACEMENT_CHARACTER
+			}
+		})
+		.collect()
+}
+
+/// Converts a codepoint index into a string to the equivalent UTF-16 code unit index.
+///
+/// A codepoint outside of the Basic Multilingual Plane is a single codepoint,
+/// but 2 code units (a surrogate pair) in UTF-16. An index equal to the
+/// number of codepoints maps to the length of the string in UTF-16.
+///
+/// This returns [`None`] if `cp_index` is past the end of the string.
+///
+/// # Examples
+/// ```
+/// use whatwg_infra::code_point_index_to_code_unit_index;
+///
+/// let s = "a\u{1F600}b";
+/// assert_eq!(code_point_index_to_code_unit_index(s, 1), Some(1));
+/// assert_eq!(code_point_index_to_code_unit_index(s, 2), Some(3));
+/// assert_eq!(code_point_index_to_code_unit_index(s, 3), Some(4));
+/// assert_eq!(code_point_index_to_code_unit_index(s, 4), None);
+/// ```
+#[must_use]
+pub fn code_point_index_to_code_unit_index(s: &str, cp_index: usize) -> Option<usize> {
+	let mut cu_index = 0usize;
+	let mut chars = s.chars();
+	for _ in 0..cp_index {
+		cu_index += chars.next()?.len_utf16();
+	}
+
+	Some(cu_index)
+}
+
+/// Converts a UTF-16 code unit index into a string to the equivalent codepoint index.
+///
+/// This is the inverse of [`code_point_index_to_code_unit_index()`], and returns
+/// [`None`] if `cu_index` is past the end of the string, or falls between the
+/// leading and trailing surrogates of a surrogate pair.
+///
+/// # Examples
+/// ```
+/// use whatwg_infra::code_unit_index_to_code_point_index;
+///
+/// let s = "a\u{1F600}b";
+/// assert_eq!(code_unit_index_to_code_point_index(s, 1), Some(1));
+/// assert_eq!(code_unit_index_to_code_point_index(s, 2), None); // within the surrogate pair
+/// assert_eq!(code_unit_index_to_code_point_index(s, 3), Some(2));
+/// assert_eq!(code_unit_index_to_code_point_index(s, 4), Some(3));
+/// ```
+#[must_use]
+pub fn code_unit_index_to_code_point_index(s: &str, cu_index: usize) -> Option<usize> {
+	let mut units = 0usize;
+	for (cp_index, c) in s.chars().enumerate() {
+		if units == cu_index {
+			return Some(cp_index);
+		} else if units > cu_index {
+			return None;
+		}
+		units += c.len_utf16();
+	}
+
+	if units == cu_index {
+		Some(s.chars().count())
+	} else {
+		None
+	}
+}
+
+/// Checks if two strings are equal after applying a folding function to
+/// each of their codepoints.
+///
+/// This compares the strings codepoint by codepoint, without allocating.
+/// See [`is_ascii_case_insensitive_match()`] for folding with
+/// [`char::to_ascii_lowercase()`].
+///
+/// # Examples
+/// ```
+/// use whatwg_infra::code_points_equal_with;
+///
+/// let fold = |c| if c == '_' { '-' } else { c };
+/// assert!(code_points_equal_with("font_size", "font-size", fold));
+/// assert!(!code_points_equal_with("font_size", "font-size", |c| c));
+/// ```
+#[must_use]
+pub fn code_points_equal_with<F>(a: &str, b: &str, fold: F) -> bool
+where
+	F: Fn(char) -> char,
+{
+	a.chars().map(&fold).eq(b.chars().map(&fold))
+}
+
+/// Checks if two strings are an [ASCII case-insensitive][whatwg-infra-dfn] match.
+///
+/// See also: [WHATWG Infra Standard definition][whatwg-infra-dfn]
+///
+/// [whatwg-infra-dfn]: https://infra.spec.whatwg.org/#ascii-case-insensitive
+///
+/// # Examples
+/// ```
+/// use whatwg_infra::is_ascii_case_insensitive_match;
+///
+/// assert!(is_ascii_case_insensitive_match("Content-Type", "content-type"));
+/// assert!(!is_ascii_case_insensitive_match("ß", "SS"));
+/// ```
+#[must_use]
+#[inline]
+pub fn is_ascii_case_insensitive_match(a: &str, b: &str) -> bool {
+	code_points_equal_with(a, b, |c| c.to_ascii_lowercase())
+}
+
+/// Counts the codepoints in a string which match a predicate function.
+///
+/// Unlike [`collect_codepoints()`], this counts across the whole string,
+/// rather than stopping at the first codepoint which doesn't match.
+///
+/// # Examples
+/// ```
+/// use whatwg_infra::count_code_points_matching;
+///
+/// assert_eq!(count_code_points_matching("2011-11-18", |c| c.is_ascii_digit()), 8);
+/// ```
+#[must_use]
+pub fn count_code_points_matching<P>(s: &str, predicate: P) -> usize
+where
+	P: Fn(char) -> bool,
+{
+	s.chars().filter(|&c| predicate(c)).count()
+}
+
+#[cfg(test)]
+mod test {
+	use super::*;
+	use crate::scalar::is_c0_control;
+	use alloc::{vec, vec::Vec};
+
+	#[test]
+	fn test_normalize_newlines() {
+		assert_eq!(
+			"\ralice\r\n\r\nbob\r".normalize_newlines(),
+			String::from("\nalice\n\nbob\n")
+		);
+	}
+
+	#[test]
+	fn test_strip_newlines_empty() {
+		assert_eq!("\r\r\n\n\r\n".strip_newlines(), String::from(""));
+	}
+
+	#[test]
+	fn test_strip_newlines_empty2() {
+		assert_eq!("".strip_newlines(), String::new());
+	}
+
+	#[test]
+	fn test_strip_newlines_strings1() {
+		assert_eq!("Alice\n\rBob".strip_newlines(), String::from("AliceBob"));
+	}
+
+	#[test]
+	fn test_trim_ascii_whitespace_empty() {
+		assert_eq!("     ".trim_ascii_whitespace(), String::from(""));
+	}
+
+	#[test]
+	fn test_trim_ascii_whitespace_strings1() {
+		assert_eq!(
+			"  cats and dogs  ".trim_ascii_whitespace(),
+			String::from("cats and dogs")
+		);
+	}
+
+	#[test]
+	fn test_trim_collapse_ascii_whitespace() {
+		assert_eq!(
+			"\r  \n  cat dog  hamster".trim_collapse_ascii_whitespace(),
+			String::from("cat dog hamster")
+		);
+	}
+
+	#[test]
+	fn test_collect_codepoints_empty() {
+		let mut position = 0usize;
+		let collected = "".collect_codepoints(&mut position, |c| c.is_ascii_whitespace());
+
+		assert_eq!(collected, String::new());
+	}
+
+	#[test]
+	fn test_collect_codepoints_high_position() {
+		let mut position = 15usize;
+		let collected = "alice".collect_codepoints(&mut position, |c| c.is_alphabetic());
+
+		assert_eq!(collected, String::new());
+	}
+
+	#[test]
+	fn test_collect_codepoints_string2() {
+		let test = "test!!!!!";
+		let mut position = 0usize;
+		let collected = test.collect_codepoints(&mut position, |c| c.is_ascii_alphabetic());
+		assert_eq!(collected, String::from("test"));
+		assert_eq!(position, 4);
+	}
+
+	#[test]
+	fn test_collect_codepoints_either() {
+		let value = "Apple    Banana    Orange";
+		let mut position = 0usize;
+		let collected = collect_codepoints(value, &mut position, |c| {
+			c.is_alphabetic() || c.is_whitespace()
+		});
+
+		assert_eq!(collected, String::from("Apple    Banana    Orange"));
+	}
+
+	#[test]
+	fn test_collect_codepoints_max() {
+		let mut position = 0usize;
+		let collected =
+			"20041".collect_codepoints_max(&mut position, |c| c.is_ascii_digit(), 2);
+
+		assert_eq!(collected, String::from("20"));
+		assert_eq!(position, 2);
+	}
+
+	#[test]
+	fn test_collect_codepoints_max_fewer_matches() {
+		let mut position = 0usize;
+		let collected =
+			"1a234".collect_codepoints_max(&mut position, |c| c.is_ascii_digit(), 2);
+
+		assert_eq!(collected, String::from("1"));
+		assert_eq!(position, 1);
+	}
+
+	#[test]
+	fn test_collect_codepoints_max_zero() {
+		let mut position = 0usize;
+		let collected =
+			"1234".collect_codepoints_max(&mut position, |c| c.is_ascii_digit(), 0);
+
+		assert_eq!(collected, String::new());
+		assert_eq!(position, 0);
+	}
+
+	#[test]
+	fn skip_codepoints() {
+		let s = "1234test";
+		let mut position = 0usize;
+
+		s.skip_codepoints(&mut position, |c| c.is_ascii_digit());
+
+		assert_eq!(position, 4);
+		assert_eq!(&s[position..], "test");
+	}
+
+	#[test]
+	fn skip_codepoints_no_matches_early_exit() {
+		let s = "1234test";
+		let mut position = 0usize;
+		s.skip_codepoints(&mut position, |c| c.is_ascii_alphabetic());
+
+		assert_eq!(position, 0);
+		assert_eq!(&s[position..], "1234test");
+	}
+
+	#[test]
+	fn skip_codepoints_match_until_end() {
+		let s = "123456789";
+		let mut position = 0usize;
+
+		s.skip_codepoints(&mut position, |c| c.is_ascii_digit());
+
+		assert_eq!(position, 9);
+		assert_eq!(&s[position..], "");
+	}
+
+	#[test]
+	fn skip_codepoints_empty_str() {
+		let s = "";
+		let mut position = 0usize;
+
+		s.skip_codepoints(&mut position, |c| c.is_ascii_digit());
+
+		assert_eq!(position, 0);
+		assert_eq!(&s[position..], "");
+	}
+
+	#[test]
+	fn skip_ascii_whitespace() {
+		let s = "   test";
+		let mut position = 0usize;
+		s.skip_ascii_whitespace(&mut position);
+
+		assert_eq!(position, 3);
+		assert_eq!(&s[position..], "test");
+	}
+
+	#[test]
+	fn impl_infrastr_for_string() {
+		assert_eq!(
+			String::from("\ralice\r\n\r\nbob\r").normalize_newlines(),
 			String::from("\nalice\n\nbob\n")
 		);
+		assert_eq!(
+			String::from("Alice\n\rBob").strip_newlines(),
+			String::from("AliceBob")
+		);
+		assert_eq!(
+			String::from("     ").trim_ascii_whitespace(),
+			String::from("")
+		);
+		assert_eq!(
+			String::from("\r  \n  cat dog  hamster").trim_collapse_ascii_whitespace(),
+			String::from("cat dog hamster")
+		);
+
+		{
+			let test = String::from("test!!!!!");
+			let mut position = 0usize;
+			let collected =
+				test.collect_codepoints(&mut position, |c| c.is_ascii_alphabetic());
+			assert_eq!(collected, String::from("test"));
+			assert_eq!(position, 4);
+		}
+
+		{
+			let s = String::from("1234test");
+			let mut position = 0usize;
+
+			s.skip_codepoints(&mut position, |c| c.is_ascii_digit());
+
+			assert_eq!(position, 4);
+			assert_eq!(&s[position..], "test");
+		}
+
+		{
+			let s = String::from("   test");
+			let mut position = 0usize;
+
+			s.skip_ascii_whitespace(&mut position);
+
+			assert_eq!(position, 3);
+			assert_eq!(&s[position..], "test");
+		}
+	}
+
+	#[test]
+	fn test_starts_with_ascii_case_insensitive() {
+		assert!("HTTPS://".starts_with_ascii_case_insensitive("https:"));
+		assert!("https://".starts_with_ascii_case_insensitive("HTTPS:"));
+		assert!("data:".starts_with_ascii_case_insensitive(""));
+	}
+
+	#[test]
+	fn test_starts_with_ascii_case_insensitive_shorter() {
+		assert!(!"http".starts_with_ascii_case_insensitive("https"));
+		assert!(!"".starts_with_ascii_case_insensitive("h"));
+	}
+
+	#[test]
+	fn test_starts_with_ascii_case_insensitive_non_ascii() {
+		// Only ASCII codepoints are case-folded
+		assert!(!"ÉCOLE".starts_with_ascii_case_insensitive("éc"));
+		assert!("école".starts_with_ascii_case_insensitive("éC"));
+		assert!(!"\u{212A}elvin".starts_with_ascii_case_insensitive("k"));
+	}
+
+	#[test]
+	fn test_strip_leading_ascii_whitespace() {
+		assert_eq!(
+			"\u{000C}\t  cats and dogs \n".strip_leading_ascii_whitespace(),
+			"cats and dogs \n"
+		);
+		assert_eq!("     ".strip_leading_ascii_whitespace(), "");
+	}
+
+	#[test]
+	fn test_strip_leading_ascii_whitespace_non_ascii() {
+		assert_eq!(
+			"\u{00A0}cats".strip_leading_ascii_whitespace(),
+			"\u{00A0}cats"
+		);
+	}
+
+	#[test]
+	fn test_strip_trailing_ascii_whitespace() {
+		assert_eq!(
+			"\n cats and dogs \u{000C}\t ".strip_trailing_ascii_whitespace(),
+			"\n cats and dogs"
+		);
+		assert_eq!("     ".strip_trailing_ascii_whitespace(), "");
+	}
+
+	#[test]
+	fn test_strip_trailing_ascii_whitespace_non_ascii() {
+		assert_eq!(
+			"cats\u{00A0}".strip_trailing_ascii_whitespace(),
+			"cats\u{00A0}"
+		);
+	}
+
+	#[test]
+	fn test_find_code_point() {
+		assert_eq!("alice".find_code_point('i'), Some(2));
+		assert_eq!("\u{1F980}\u{00E9}i".find_code_point('i'), Some(2));
+		assert_eq!("\u{1F980}\u{00E9}i".find_code_point('\u{00E9}'), Some(1));
+		assert_eq!("alice".find_code_point('z'), None);
+		assert_eq!("".find_code_point('z'), None);
+	}
+
+	#[test]
+	fn test_rfind_code_point() {
+		assert_eq!("a-b-c".rfind_code_point('-'), Some(3));
+		assert_eq!("\u{1F980}-\u{00E9}-c".rfind_code_point('-'), Some(3));
+		assert_eq!("\u{1F980}\u{00E9}".rfind_code_point('-'), None);
+	}
+
+	#[test]
+	fn test_chars_with_positions() {
+		let s = "a\u{00E9}\u{1F980}b";
+		let positions: Vec<(usize, usize, char)> = s.chars_with_positions().collect();
+
+		assert_eq!(
+			positions,
+			vec![
+				(0, 0, 'a'),
+				(1, 1, '\u{00E9}'),
+				(3, 2, '\u{1F980}'),
+				(7, 3, 'b'),
+			]
+		);
+		for (byte_index, _, c) in positions {
+			assert!(s[byte_index..].starts_with(c));
+		}
+	}
+
+	#[test]
+	fn test_chars_with_positions_empty() {
+		assert_eq!("".chars_with_positions().next(), None);
+	}
+
+	#[test]
+	fn test_ascii_lowercase_in_place() {
+		let mut s = String::with_capacity(64);
+		s.push_str("Hello, WORLD! \u{C9}");
+		let (ptr, capacity) = (s.as_ptr(), s.capacity());
+
+		ascii_lowercase_in_place(&mut s);
+		assert_eq!(s, "hello, world! \u{C9}");
+		assert_eq!(s.as_ptr(), ptr);
+		assert_eq!(s.capacity(), capacity);
+	}
+
+	#[test]
+	fn test_ascii_uppercase_in_place() {
+		let mut s = String::with_capacity(64);
+		s.push_str("Hello, world! \u{E9}");
+		let (ptr, capacity) = (s.as_ptr(), s.capacity());
+
+		ascii_uppercase_in_place(&mut s);
+		assert_eq!(s, "HELLO, WORLD! \u{E9}");
+		assert_eq!(s.as_ptr(), ptr);
+		assert_eq!(s.capacity(), capacity);
+	}
+
+	#[test]
+	fn test_collect_until_match() {
+		let mut position = 0usize;
+		let collected =
+			collect_until_match("abc123", &mut position, |c| c.is_ascii_digit());
+		assert_eq!(collected, String::from("abc"));
+		assert_eq!(position, 3);
+
+		let collected =
+			String::from("abc123").collect_until_match(&mut position, |c| c == '3');
+		assert_eq!(collected, String::from("12"));
+		assert_eq!(position, 5);
+	}
+
+	#[test]
+	fn test_collect_until_match_no_match() {
+		let mut position = 0usize;
+		let collected = "abc".collect_until_match(&mut position, |c| c.is_ascii_digit());
+		assert_eq!(collected, String::from("abc"));
+		assert_eq!(position, 3);
+	}
+
+	#[test]
+	fn test_collect_until_match_immediate_match() {
+		let mut position = 0usize;
+		let collected = collect_until_match("1abc", &mut position, |c| c.is_ascii_digit());
+		assert_eq!(collected, String::new());
+		assert_eq!(position, 0);
 	}
 
 	#[test]
-	fn test_strip_newlines_empty() {
-		assert_eq!("\r\r\n\n\r\n".strip_newlines(), String::from(""));
+	fn test_skip_codepoints_max() {
+		let mut position = 0usize;
+		skip_codepoints_max("   alice", &mut position, |c| c == ' ', 1);
+		assert_eq!(position, 1);
+
+		"   alice".skip_codepoints_max(&mut position, |c| c == ' ', 5);
+		assert_eq!(position, 3);
 	}
 
 	#[test]
-	fn test_strip_newlines_empty2() {
-		assert_eq!("".strip_newlines(), String::new());
+	fn test_skip_codepoints_max_zero() {
+		let mut position = 0usize;
+		String::from("   alice").skip_codepoints_max(&mut position, |c| c == ' ', 0);
+		assert_eq!(position, 0);
 	}
 
 	#[test]
-	fn test_strip_newlines_strings1() {
-		assert_eq!("Alice\n\rBob".strip_newlines(), String::from("AliceBob"));
+	fn test_find_invalid_code_points() {
+		let s = "caf\u{e9}\u{7}ok\u{1B}";
+		let invalid = find_invalid_code_points(s, |c| !crate::is_c0_control(c));
+		assert_eq!(invalid, vec![(4, '\u{7}'), (7, '\u{1B}')]);
 	}
 
 	#[test]
-	fn test_trim_ascii_whitespace_empty() {
-		assert_eq!("     ".trim_ascii_whitespace(), String::from(""));
+	fn test_find_invalid_code_points_none() {
+		assert!("hello"
+			.find_invalid_code_points(|c| c.is_ascii())
+			.is_empty());
+		assert!(String::new().find_invalid_code_points(|_| false).is_empty());
 	}
 
 	#[test]
-	fn test_trim_ascii_whitespace_strings1() {
-		assert_eq!(
-			"  cats and dogs  ".trim_ascii_whitespace(),
-			String::from("cats and dogs")
-		);
+	fn test_utf16_len_of_prefix() {
+		assert_eq!(utf16_len_of_prefix("", 0), Some(0));
+		assert_eq!(utf16_len_of_prefix("abc", 3), Some(3));
+		assert_eq!(utf16_len_of_prefix("caf\u{e9}!", 5), Some(4));
 	}
 
 	#[test]
-	fn test_trim_collapse_ascii_whitespace() {
+	fn test_utf16_len_of_prefix_astral() {
+		let s = "\u{1F600}\u{10348}x";
+		assert_eq!(s.utf16_len_of_prefix(4), Some(2));
+		assert_eq!(s.utf16_len_of_prefix(8), Some(4));
+		assert_eq!(String::from(s).utf16_len_of_prefix(9), Some(5));
+	}
+
+	#[test]
+	fn test_utf16_len_of_prefix_fails() {
+		assert_eq!(utf16_len_of_prefix("abc", 4), None);
+		assert_eq!(utf16_len_of_prefix("\u{1F600}", 1), None);
+		assert_eq!(utf16_len_of_prefix("\u{e9}", 1), None);
+	}
+
+	#[test]
+	fn test_collect_codepoints_cow() {
+		let mut position = 0usize;
+		let collected =
+			collect_codepoints_cow("test1", &mut position, |c| c.is_ascii_alphabetic());
+		assert!(matches!(collected, Cow::Borrowed("test")));
+		assert_eq!(position, 4);
+	}
+
+	#[test]
+	fn test_collect_codepoints_cow_matches_collect_codepoints() {
+		for (s, start) in [
+			("test1", 0),
+			("test1", 2),
+			("1test", 0),
+			("", 0),
+			("test", 4),
+			("\u{E9}", 0),
+			("\u{E9}a1", 0),
+			("1\u{E9}a", 1),
+			("a\u{1F600}b", 2),
+		] {
+			let mut cow_position = start;
+			let mut owned_position = start;
+			assert_eq!(
+				collect_codepoints_cow(s, &mut cow_position, char::is_alphabetic),
+				collect_codepoints(s, &mut owned_position, char::is_alphabetic)
+			);
+			assert_eq!(cow_position, owned_position);
+		}
+	}
+
+	#[test]
+	fn test_collect_codepoints_cow_non_ascii() {
+		let mut position = 0usize;
 		assert_eq!(
-			"\r  \n  cat dog  hamster".trim_collapse_ascii_whitespace(),
-			String::from("cat dog hamster")
+			collect_codepoints_cow("\u{E9}", &mut position, char::is_alphabetic),
+			Cow::Borrowed("\u{E9}")
+		);
+		assert_eq!(position, 1);
+
+		let mut position = 0usize;
+		assert_eq!(
+			collect_codepoints_cow("\u{E9}a1", &mut position, char::is_alphabetic),
+			Cow::Borrowed("\u{E9}a")
 		);
+		assert_eq!(position, 2);
 	}
 
 	#[test]
-	fn test_collect_codepoints_empty() {
+	fn test_collect_codepoints_cow_trait() {
+		let s = String::from("abc def");
 		let mut position = 0usize;
-		let collected = "".collect_codepoints(&mut position, |c| c.is_ascii_whitespace());
+		assert_eq!(s.collect_codepoints_cow(&mut position, |c| c != ' '), "abc");
+		assert_eq!(position, 3);
+	}
 
-		assert_eq!(collected, String::new());
+	#[test]
+	fn test_retain_matching_removes_c0_controls() {
+		let mut s = String::from("\u{0}line\u{7}one\r\nline\ttwo\u{1F} \u{7F}");
+		retain_matching(&mut s, |c| !crate::is_c0_control(c));
+		assert_eq!(s, "lineonelinetwo \u{7F}");
 	}
 
 	#[test]
-	fn test_collect_codepoints_high_position() {
-		let mut position = 15usize;
-		let collected = "alice".collect_codepoints(&mut position, |c| c.is_alphabetic());
+	fn test_retain_matching_keeps_all() {
+		let mut s = String::from("caf\u{E9}");
+		retain_matching(&mut s, |_| true);
+		assert_eq!(s, "caf\u{E9}");
 
-		assert_eq!(collected, String::new());
+		retain_matching(&mut s, |_| false);
+		assert_eq!(s, "");
 	}
 
 	#[test]
-	fn test_collect_codepoints_string2() {
-		let test = "test!!!!!";
-		let mut position = 0usize;
-		let collected = test.collect_codepoints(&mut position, |c| c.is_ascii_alphabetic());
-		assert_eq!(collected, String::from("test"));
-		assert_eq!(position, 4);
+	fn test_strip_utf8_bom() {
+		let s = "\u{FEFF}cats";
+		let stripped = strip_utf8_bom(s);
+		assert_eq!(stripped, "cats");
+		assert_eq!(stripped.as_ptr(), s[3..].as_ptr());
 	}
 
 	#[test]
-	fn test_collect_codepoints_either() {
-		let value = "Apple    Banana    Orange";
-		let mut position = 0usize;
-		let collected = collect_codepoints(value, &mut position, |c| {
-			c.is_alphabetic() || c.is_whitespace()
-		});
+	fn test_strip_utf8_bom_without_bom() {
+		let s = "cats\u{FEFF}";
+		let stripped = strip_utf8_bom(s);
+		assert_eq!(stripped, s);
+		assert_eq!(stripped.as_ptr(), s.as_ptr());
+		assert_eq!(strip_utf8_bom(""), "");
+	}
 
-		assert_eq!(collected, String::from("Apple    Banana    Orange"));
+	#[test]
+	fn test_strip_utf8_bom_only_once() {
+		assert_eq!(strip_utf8_bom("\u{FEFF}\u{FEFF}cats"), "\u{FEFF}cats");
+		assert_eq!(String::from("\u{FEFF}cats").strip_utf8_bom(), "cats");
 	}
 
 	#[test]
-	fn skip_codepoints() {
-		let s = "1234test";
-		let mut position = 0usize;
+	fn test_split_at_code_point() {
+		assert_eq!(split_at_code_point("abc", 0), Some(("", "abc")));
+		assert_eq!(split_at_code_point("abc", 1), Some(("a", "bc")));
+		assert_eq!(split_at_code_point("abc", 3), Some(("abc", "")));
+		assert_eq!(split_at_code_point("", 0), Some(("", "")));
+	}
 
-		s.skip_codepoints(&mut position, |c| c.is_ascii_digit());
+	#[test]
+	fn test_split_at_code_point_multibyte() {
+		let s = "a\u{E9}\u{1F600}b";
+		assert_eq!(split_at_code_point(s, 1), Some(("a", "\u{E9}\u{1F600}b")));
+		assert_eq!(split_at_code_point(s, 2), Some(("a\u{E9}", "\u{1F600}b")));
+		assert_eq!(split_at_code_point(s, 3), Some(("a\u{E9}\u{1F600}", "b")));
+		assert_eq!(split_at_code_point(s, 4), Some((s, "")));
+	}
 
-		assert_eq!(position, 4);
-		assert_eq!(&s[position..], "test");
+	#[test]
+	fn test_split_at_code_point_fails_out_of_range() {
+		assert_eq!(split_at_code_point("a\u{1F600}", 3), None);
+		assert_eq!(split_at_code_point("", 1), None);
 	}
 
 	#[test]
-	fn skip_codepoints_no_matches_early_exit() {
-		let s = "1234test";
-		let mut position = 0usize;
-		s.skip_codepoints(&mut position, |c| c.is_ascii_alphabetic());
+	fn test_split_at_code_point_trait() {
+		let s = String::from("\u{1F600}\u{1F600}");
+		assert_eq!(s.split_at_code_point(1), Some(("\u{1F600}", "\u{1F600}")));
+	}
 
-		assert_eq!(position, 0);
-		assert_eq!(&s[position..], "1234test");
+	#[test]
+	fn test_trim_ascii_whitespace_counts() {
+		assert_eq!(trim_ascii_whitespace_counts("  abc   "), (2, "abc", 3));
+		assert_eq!(
+			trim_ascii_whitespace_counts("\t\n\x0C\rabc \u{E9} "),
+			(4, "abc \u{E9}", 1)
+		);
+		assert_eq!(trim_ascii_whitespace_counts("abc"), (0, "abc", 0));
 	}
 
 	#[test]
-	fn skip_codepoints_match_until_end() {
-		let s = "123456789";
-		let mut position = 0usize;
+	fn test_trim_ascii_whitespace_counts_all_whitespace() {
+		assert_eq!(trim_ascii_whitespace_counts("     "), (5, "", 0));
+		assert_eq!(trim_ascii_whitespace_counts(""), (0, "", 0));
+	}
 
-		s.skip_codepoints(&mut position, |c| c.is_ascii_digit());
+	#[test]
+	fn test_trim_ascii_whitespace_counts_ignores_non_ascii_whitespace() {
+		assert_eq!(
+			trim_ascii_whitespace_counts("\u{A0} abc \u{3000}"),
+			(0, "\u{A0} abc \u{3000}", 0)
+		);
+	}
 
-		assert_eq!(position, 9);
-		assert_eq!(&s[position..], "");
+	#[test]
+	fn test_trim_ascii_whitespace_counts_position() {
+		let s = "  14:54 ";
+		let (leading, trimmed, _) = trim_ascii_whitespace_counts(s);
+		let position = trimmed.find(':').unwrap();
+		assert_eq!(s[leading + position..].chars().next(), Some(':'));
 	}
 
 	#[test]
-	fn skip_codepoints_empty_str() {
-		let s = "";
+	fn test_collect_codepoints_and_peek_delimiter() {
+		let s = "14:54";
 		let mut position = 0usize;
+		assert_eq!(
+			collect_codepoints_and_peek(s, &mut position, |c| c.is_ascii_digit()),
+			("14", Some(':'))
+		);
+		assert_eq!(position, 2);
 
-		s.skip_codepoints(&mut position, |c| c.is_ascii_digit());
+		// The stopping codepoint isn't consumed
+		assert_eq!(
+			collect_codepoints_and_peek(s, &mut position, |c| c.is_ascii_digit()),
+			("", Some(':'))
+		);
+		assert_eq!(position, 2);
+	}
 
-		assert_eq!(position, 0);
-		assert_eq!(&s[position..], "");
+	#[test]
+	fn test_collect_codepoints_and_peek_end_of_string() {
+		let mut position = 3usize;
+		assert_eq!(
+			collect_codepoints_and_peek("14:54", &mut position, |c| c.is_ascii_digit()),
+			("54", None)
+		);
+		assert_eq!(position, 5);
+
+		assert_eq!(
+			collect_codepoints_and_peek("14:54", &mut position, |c| c.is_ascii_digit()),
+			("", None)
+		);
+		assert_eq!(position, 5);
 	}
 
 	#[test]
-	fn skip_ascii_whitespace() {
-		let s = "   test";
+	fn test_collect_codepoints_and_peek_multibyte() {
+		let s = "caf\u{E9}\u{2010}s";
 		let mut position = 0usize;
-		s.skip_ascii_whitespace(&mut position);
+		assert_eq!(
+			collect_codepoints_and_peek(s, &mut position, |c| c.is_alphabetic()),
+			("caf\u{E9}", Some('\u{2010}'))
+		);
+		assert_eq!(position, 4);
 
-		assert_eq!(position, 3);
-		assert_eq!(&s[position..], "test");
+		// Positions are codepoint indices, like the other collection functions
+		super::skip_codepoints(s, &mut position, |c| c == '\u{2010}');
+		assert_eq!(
+			collect_codepoints_and_peek(s, &mut position, |c| c.is_alphabetic()),
+			("s", None)
+		);
+		assert_eq!(position, 6);
 	}
 
 	#[test]
-	fn impl_infrastr_for_string() {
+	fn test_collect_codepoints_and_peek_out_of_range() {
+		let mut position = 10usize;
 		assert_eq!(
-			String::from("\ralice\r\n\r\nbob\r").normalize_newlines(),
-			String::from("\nalice\n\nbob\n")
+			collect_codepoints_and_peek("abc", &mut position, |_| true),
+			("", None)
 		);
+		assert_eq!(position, 10);
+	}
+
+	#[test]
+	fn test_ascii_collation_key() {
+		assert_eq!(ascii_collation_key("  Hello   World "), "hello world");
 		assert_eq!(
-			String::from("Alice\n\rBob").strip_newlines(),
-			String::from("AliceBob")
+			ascii_collation_key("  Hello   World "),
+			ascii_collation_key("hello world")
 		);
 		assert_eq!(
-			String::from("     ").trim_ascii_whitespace(),
-			String::from("")
+			ascii_collation_key("HELLO\t\nworld"),
+			ascii_collation_key("hello world")
 		);
+	}
+
+	#[test]
+	fn test_ascii_collation_key_non_ascii() {
+		// Non-ASCII case and whitespace are kept as-is
+		assert_eq!(ascii_collation_key("CAF\u{C9}"), "caf\u{C9}");
+		assert_ne!(ascii_collation_key("a\u{A0}b"), ascii_collation_key("a b"));
+		assert_eq!(ascii_collation_key(""), "");
+		assert_eq!(ascii_collation_key("   "), "");
+	}
+
+	#[test]
+	fn test_at_end() {
+		assert!(at_end("", 0));
+		assert!(!at_end("abc", 2));
+		assert!(at_end("abc", 3));
+		assert!(at_end("abc", 4));
+		assert!(!at_end("\u{E9}\u{E9}", 1));
+		assert!(at_end("\u{E9}\u{E9}", 2));
+	}
+
+	#[test]
+	fn test_remaining() {
+		assert_eq!(remaining("abc", 0), "abc");
+		assert_eq!(remaining("abc", 2), "c");
+		assert_eq!(remaining("abc", 3), "");
+		assert_eq!(remaining("abc", 4), "");
+		assert_eq!(remaining("\u{E9}", 1), "");
+		assert_eq!(remaining("\u{E9}12", 1), "12");
+		assert_eq!(remaining("a\u{1F600}b", 2), "b");
+	}
+
+	#[test]
+	fn test_collect_codepoints_non_ascii() {
+		let mut position = 0usize;
 		assert_eq!(
-			String::from("\r  \n  cat dog  hamster").trim_collapse_ascii_whitespace(),
-			String::from("cat dog hamster")
+			collect_codepoints("\u{E9}", &mut position, char::is_alphabetic),
+			"\u{E9}"
 		);
+		assert_eq!(position, 1);
 
-		{
-			let test = String::from("test!!!!!");
-			let mut position = 0usize;
-			let collected =
-				test.collect_codepoints(&mut position, |c| c.is_ascii_alphabetic());
-			assert_eq!(collected, String::from("test"));
-			assert_eq!(position, 4);
-		}
+		let mut position = 0usize;
+		assert_eq!(
+			collect_codepoints("\u{E9}a1", &mut position, char::is_alphabetic),
+			"\u{E9}a"
+		);
+		assert_eq!(position, 2);
 
-		{
-			let s = String::from("1234test");
-			let mut position = 0usize;
+		let mut position = 1usize;
+		assert_eq!(
+			collect_codepoints("\u{E9}12", &mut position, |c| c.is_ascii_digit()),
+			"12"
+		);
+		assert_eq!(position, 3);
+	}
 
-			s.skip_codepoints(&mut position, |c| c.is_ascii_digit());
+	#[test]
+	fn test_collect_codepoints_position_at_end() {
+		let mut position = 3usize;
+		assert_eq!(collect_codepoints("abc", &mut position, |_| true), "");
+		assert_eq!(position, 3);
 
-			assert_eq!(position, 4);
-			assert_eq!(&s[position..], "test");
-		}
+		let mut position = 3usize;
+		assert_eq!(collect_codepoints_cow("abc", &mut position, |_| true), "");
+		assert_eq!(position, 3);
+	}
 
-		{
-			let s = String::from("   test");
-			let mut position = 0usize;
+	#[test]
+	fn test_collect_codepoints_position_past_end() {
+		let mut position = 10usize;
+		assert_eq!(collect_codepoints("abc", &mut position, |_| true), "");
+		assert_eq!(position, 10);
 
-			s.skip_ascii_whitespace(&mut position);
+		let mut position = 10usize;
+		assert_eq!(
+			collect_codepoints_max("abc", &mut position, |_| true, 2),
+			""
+		);
+		assert_eq!(position, 10);
+	}
 
-			assert_eq!(position, 3);
-			assert_eq!(&s[position..], "test");
+	#[test]
+	fn test_skip_codepoints_position_at_and_past_end() {
+		let mut position = 3usize;
+		super::skip_codepoints("abc", &mut position, |_| true);
+		assert_eq!(position, 3);
+
+		let mut position = 10usize;
+		super::skip_codepoints("abc", &mut position, |_| true);
+		assert_eq!(position, 10);
+
+		let mut position = 10usize;
+		skip_codepoints_max("abc", &mut position, |_| true, 2);
+		assert_eq!(position, 10);
+	}
+
+	#[test]
+	fn test_whitespace_runs() {
+		let s = "  a b\t\r\nc\x0C";
+		let runs: Vec<(usize, usize)> = whitespace_runs(s).collect();
+		assert_eq!(runs, vec![(0, 2), (3, 4), (5, 8), (9, 10)]);
+		assert_eq!(&s[5..8], "\t\r\n");
+	}
+
+	#[test]
+	fn test_whitespace_runs_none() {
+		assert_eq!(whitespace_runs("").next(), None);
+		assert_eq!(whitespace_runs("abc").next(), None);
+	}
+
+	#[test]
+	fn test_whitespace_runs_non_ascii() {
+		let s = "é \u{00A0} ü";
+		let runs: Vec<(usize, usize)> = whitespace_runs(s).collect();
+		assert_eq!(runs, vec![(2, 3), (5, 6)]);
+	}
+
+	#[test]
+	fn test_eq_normalized_newlines() {
+		assert!(eq_normalized_newlines("a\r\nb", "a\nb"));
+		assert!(eq_normalized_newlines("a\r\nb", "a\rb"));
+		assert!(eq_normalized_newlines("a\nb", "a\rb"));
+		assert!(eq_normalized_newlines("\r\n\r\r\n", "\n\n\n"));
+		assert!(eq_normalized_newlines("", ""));
+		assert!("a\r\nb".eq_normalized_newlines("a\nb"));
+	}
+
+	#[test]
+	fn test_eq_normalized_newlines_fails() {
+		assert!(!eq_normalized_newlines("a\r\nb", "a\n\nb"));
+		assert!(!eq_normalized_newlines("a\n\rb", "a\nb"));
+		assert!(!eq_normalized_newlines("a\r\n", "a"));
+		assert!(!eq_normalized_newlines("a\nb", "a b"));
+	}
+
+	#[test]
+	fn test_sanitize_c0_controls() {
+		let s = "\u{0}héllo\u{7}\tworld\u{1F}";
+		assert_eq!(
+			sanitize(s, |c| !is_c0_control(c)),
+			"\u{FFFD}héllo\u{FFFD}\u{FFFD}world\u{FFFD}"
+		);
+		assert_eq!(
+			s.sanitize(|c| !is_c0_control(c) || c == '\t'),
+			"\u{FFFD}héllo\u{FFFD}\tworld\u{FFFD}"
+		);
+	}
+
+	#[test]
+	fn test_sanitize_preserves_valid() {
+		assert_eq!(sanitize("cats and dogs", |_| true), "cats and dogs");
+		assert_eq!(sanitize("", |_| false), "");
+	}
+
+	#[test]
+	fn test_code_point_index_to_code_unit_index() {
+		let s = "\u{1F600}a\u{10000}\u{10FFFF}";
+		assert_eq!(code_point_index_to_code_unit_index(s, 0), Some(0));
+		assert_eq!(code_point_index_to_code_unit_index(s, 1), Some(2));
+		assert_eq!(code_point_index_to_code_unit_index(s, 2), Some(3));
+		assert_eq!(code_point_index_to_code_unit_index(s, 3), Some(5));
+		assert_eq!(s.code_point_index_to_code_unit_index(4), Some(7));
+		assert_eq!(code_point_index_to_code_unit_index(s, 5), None);
+		assert_eq!(code_point_index_to_code_unit_index("", 0), Some(0));
+	}
+
+	#[test]
+	fn test_code_unit_index_to_code_point_index() {
+		let s = "\u{1F600}a\u{10000}\u{10FFFF}";
+		assert_eq!(code_unit_index_to_code_point_index(s, 0), Some(0));
+		assert_eq!(code_unit_index_to_code_point_index(s, 2), Some(1));
+		assert_eq!(code_unit_index_to_code_point_index(s, 3), Some(2));
+		assert_eq!(code_unit_index_to_code_point_index(s, 5), Some(3));
+		assert_eq!(s.code_unit_index_to_code_point_index(7), Some(4));
+		assert_eq!(code_unit_index_to_code_point_index(s, 8), None);
+		assert_eq!(code_unit_index_to_code_point_index("", 0), Some(0));
+	}
+
+	#[test]
+	fn test_code_unit_index_to_code_point_index_splits_surrogate_pair() {
+		let s = "\u{1F600}a\u{10000}\u{10FFFF}";
+		assert_eq!(code_unit_index_to_code_point_index(s, 1), None);
+		assert_eq!(code_unit_index_to_code_point_index(s, 4), None);
+		assert_eq!(code_unit_index_to_code_point_index(s, 6), None);
+	}
+
+	#[test]
+	fn test_code_unit_index_round_trip() {
+		let s = "é\u{1F600}x\u{10FFFF}";
+		for cp_index in 0..=s.chars().count() {
+			let cu_index = code_point_index_to_code_unit_index(s, cp_index).unwrap();
+			assert_eq!(
+				code_unit_index_to_code_point_index(s, cu_index),
+				Some(cp_index)
+			);
 		}
 	}
+
+	#[test]
+	fn test_code_points_equal_with_custom_fold() {
+		let fold = |c| if c == '_' { '-' } else { c };
+		assert!(code_points_equal_with("a-b_c", "a_b-c", fold));
+		assert!(code_points_equal_with("", "", fold));
+		assert!("a_b".code_points_equal_with("a-b", fold));
+		assert!(!code_points_equal_with("a_b", "a-B", fold));
+		assert!(!code_points_equal_with("a_b", "a-b-", fold));
+	}
+
+	#[test]
+	fn test_code_points_equal_with_identity() {
+		assert!(code_points_equal_with("héllo", "héllo", |c| c));
+		assert!(!code_points_equal_with("héllo", "hello", |c| c));
+	}
+
+	#[test]
+	fn test_is_ascii_case_insensitive_match() {
+		assert!(is_ascii_case_insensitive_match("HeLLo", "hello"));
+		assert!("UTF-8".is_ascii_case_insensitive_match("utf-8"));
+		assert!(!is_ascii_case_insensitive_match("É", "é"));
+		assert!(!is_ascii_case_insensitive_match("hello", "hell"));
+	}
+
+	#[test]
+	fn test_count_code_points_matching() {
+		let s = "a1b22 c333é4";
+		assert_eq!(count_code_points_matching(s, |c| c.is_ascii_digit()), 7);
+		assert_eq!(s.count_code_points_matching(|c| c.is_ascii_alphabetic()), 3);
+		assert_eq!(count_code_points_matching(s, |c| !c.is_ascii()), 1);
+		assert_eq!(count_code_points_matching("", |_| true), 0);
+	}
+
+	#[test]
+	fn test_normalize_newlines_to() {
+		let s = "a\r\nb\rc\nd\r\n\re";
+		assert_eq!(
+			normalize_newlines_to(s, NewlineStyle::Lf),
+			"a\nb\nc\nd\n\ne"
+		);
+		assert_eq!(
+			normalize_newlines_to(s, NewlineStyle::CrLf),
+			"a\r\nb\r\nc\r\nd\r\n\r\ne"
+		);
+		assert_eq!(
+			normalize_newlines_to(s, NewlineStyle::Cr),
+			"a\rb\rc\rd\r\re"
+		);
+	}
+
+	#[test]
+	fn test_normalize_newlines_to_lf_matches_normalize_newlines() {
+		let s = "\ralice\r\n\r\nbob\r\n\n";
+		assert_eq!(
+			normalize_newlines_to(s, NewlineStyle::Lf),
+			normalize_newlines(s)
+		);
+		assert_eq!(normalize_newlines_to("", NewlineStyle::CrLf), "");
+		assert_eq!(
+			normalize_newlines_to("no breaks", NewlineStyle::Cr),
+			"no breaks"
+		);
+	}
 }
@@ -1,3 +1,6 @@
+extern crate alloc;
+use alloc::{string::String, vec::Vec};
+
 /// Methods from the WHATWG Infra Standard for UTF-16 surrogates
 #[allow(clippy::wrong_self_convention)]
 pub trait InfraUtf16Surrogate {
@@ -120,32 +123,126 @@ pub const fn is_trailing_surrogate_utf16(c: u16) -> bool {
 	matches!(c, u16::TRAILING_SURROGATE_MIN..=u16::TRAILING_SURROGATE_MAX)
 }
 
+/// Encodes a string into a sequence of UTF-16 code units, where each
+/// codepoint above U+FFFF is encoded as a leading and trailing surrogate.
+///
+/// # Examples
+/// ```
+/// use whatwg_infra::surrogates::encode_utf16_vec;
+///
+/// assert_eq!(encode_utf16_vec("a\u{1F600}"), vec![0x0061, 0xD83D, 0xDE00]);
+/// ```
+#[must_use]
+#[inline]
+pub fn encode_utf16_vec(s: &str) -> Vec<u16> {
+	s.encode_utf16().collect()
+}
+
+/// Decodes a sequence of UTF-16 code units into a string, replacing each
+/// [surrogate][whatwg-infra-dfn] which isn't part of a surrogate pair
+/// with U+FFFD REPLACEMENT CHARACTER.
+///
+/// [whatwg-infra-dfn]: https://infra.spec.whatwg.org/#surrogate
+///
+/// # Examples
+/// ```
+/// use whatwg_infra::surrogates::decode_utf16_lossy;
+///
+/// assert_eq!(decode_utf16_lossy(&[0x0061, 0xD83D, 0xDE00]), "a\u{1F600}");
+/// assert_eq!(decode_utf16_lossy(&[0x0061, 0xD83D, 0x0062]), "a\u{FFFD}b");
+/// ```
+#[must_use]
+pub fn decode_utf16_lossy(units: &[u16]) -> String {
+	let mut result = String::with_capacity(units.len());
+	let mut i = 0usize;
+	while i < units.len() {
+		let unit = units[i];
+		i += 1;
+
+		if !is_surrogate_utf16(unit) {
+			result.push(char::from_u32(u32::from(unit))
+				.unwrap_or(char::REPLACEMENT_CHARACTER));
+			continue;
+		}
+
+		match units.get(i) {
+			Some(&trailing)
+				if is_leading_surrogate_utf16(unit)
+					&& is_trailing_surrogate_utf16(trailing) =>
+			{
+				let code_point = 0x10000
+					+ ((u32::from(unit)
+						- u32::from(u16::LEADING_SURROGATE_MIN)) << 10)
+					+ (u32::from(trailing)
+						- u32::from(u16::TRAILING_SURROGATE_MIN));
+				result.push(char::from_u32(code_point)
+					.unwrap_or(char::REPLACEMENT_CHARACTER));
+				i += 1;
+			}
+			_ => result.push(char::REPLACEMENT_CHARACTER),
+		}
+	}
+
+	result
+}
+
 #[cfg(test)]
 mod tests {
 	use super::*;
 
 	#[test]
 	fn test_is_surrogate_utf16() {
-		assert_eq!(is_surrogate_utf16(0xD799u16), false);
-		assert_eq!(is_surrogate_utf16(0xD809u16), true);
-		assert_eq!(is_surrogate_utf16(0xDB99u16), true);
-		assert_eq!(is_surrogate_utf16(0xDFFFu16), true);
-		assert_eq!(0xE000u16.is_surrogate_utf16(), false);
+		assert!(!is_surrogate_utf16(0xD799u16));
+		assert!(is_surrogate_utf16(0xD809u16));
+		assert!(is_surrogate_utf16(0xDB99u16));
+		assert!(is_surrogate_utf16(0xDFFFu16));
+		assert!(!0xE000u16.is_surrogate_utf16());
 	}
 
 	#[test]
 	fn test_is_leading_surrogate_utf16() {
-		assert_eq!(is_leading_surrogate_utf16(0xD799u16), false);
-		assert_eq!(is_leading_surrogate_utf16(0xD800u16), true);
-		assert_eq!(is_leading_surrogate_utf16(0xDBFFu16), true);
-		assert_eq!(0xDC00u16.is_leading_surrogate_utf16(), false);
+		assert!(!is_leading_surrogate_utf16(0xD799u16));
+		assert!(is_leading_surrogate_utf16(0xD800u16));
+		assert!(is_leading_surrogate_utf16(0xDBFFu16));
+		assert!(!0xDC00u16.is_leading_surrogate_utf16());
 	}
 
 	#[test]
 	fn test_is_trailing_surrogate_utf16() {
-		assert_eq!(is_trailing_surrogate_utf16(0xDB99u16), false);
-		assert_eq!(is_trailing_surrogate_utf16(0xDC00u16), true);
-		assert_eq!(is_trailing_surrogate_utf16(0xDFFFu16), true);
-		assert_eq!(0xE000u16.is_trailing_surrogate_utf16(), false);
+		assert!(!is_trailing_surrogate_utf16(0xDB99u16));
+		assert!(is_trailing_surrogate_utf16(0xDC00u16));
+		assert!(is_trailing_surrogate_utf16(0xDFFFu16));
+		assert!(!0xE000u16.is_trailing_surrogate_utf16());
+	}
+
+	#[test]
+	fn test_encode_utf16_vec() {
+		assert_eq!(encode_utf16_vec(""), Vec::<u16>::new());
+		assert_eq!(encode_utf16_vec("ab"), alloc::vec![0x0061, 0x0062]);
+		assert_eq!(encode_utf16_vec("\u{1F600}"), alloc::vec![0xD83D, 0xDE00]);
+	}
+
+	#[test]
+	fn test_decode_utf16_lossy_valid_pair() {
+		assert_eq!(decode_utf16_lossy(&[0xD83D, 0xDE00]), "\u{1F600}");
+		assert_eq!(decode_utf16_lossy(&[0xD800, 0xDC00]), "\u{10000}");
+		assert_eq!(decode_utf16_lossy(&[0xDBFF, 0xDFFF]), "\u{10FFFF}");
+	}
+
+	#[test]
+	fn test_decode_utf16_lossy_lone_surrogate() {
+		assert_eq!(decode_utf16_lossy(&[0xD83D]), "\u{FFFD}");
+		assert_eq!(decode_utf16_lossy(&[0xDE00, 0x0061]), "\u{FFFD}a");
+		assert_eq!(decode_utf16_lossy(&[0xDE00, 0xD83D]), "\u{FFFD}\u{FFFD}");
+		assert_eq!(
+			decode_utf16_lossy(&[0xD83D, 0xD83D, 0xDE00]),
+			"\u{FFFD}\u{1F600}"
+		);
+	}
+
+	#[test]
+	fn test_decode_utf16_lossy_round_trip() {
+		let s = "caf\u{E9} \u{1F600}";
+		assert_eq!(decode_utf16_lossy(&encode_utf16_vec(s)), s);
 	}
 }